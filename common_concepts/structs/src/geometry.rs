@@ -0,0 +1,17 @@
+pub mod point;
+pub mod vector;
+pub mod quaternion;
+pub mod placed;
+pub mod aabb;
+pub mod contact;
+pub mod overlap;
+pub mod csg;
+
+pub use point::{Point2, Point3};
+pub use vector::Vector3;
+pub use quaternion::Quaternion;
+pub use placed::Placed;
+pub use aabb::Aabb;
+pub use contact::{Collide, Contact, Contains};
+pub use overlap::Overlap;
+pub use csg::{Csg, Region, VolumeEstimate};
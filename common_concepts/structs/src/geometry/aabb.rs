@@ -0,0 +1,149 @@
+use std::fmt::{self, Display};
+
+use super::Point3;
+use crate::{
+    shapes::{Prism, Shape3D},
+    utils::{Ops, Scalar},
+};
+
+/// An axis-aligned bounding box, the smallest box with edges along x, y and z around a shape
+///
+/// Comparing boxes is much cheaper than comparing the shapes inside them, so they are used
+/// to rule out overlaps quickly before any exact test.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aabb<T>
+where T:
+    Scalar
+{
+    min: Point3<T>,
+    max: Point3<T>,
+}
+
+impl<T> Aabb<T>
+where T:
+    Scalar
+{
+    /// Builds the box with two opposite corners at `a` and `b`
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        Self {
+            min: Point3::new(Ops::min(a.x, b.x), Ops::min(a.y, b.y), Ops::min(a.z, b.z)),
+            max: Point3::new(Ops::max(a.x, b.x), Ops::max(a.y, b.y), Ops::max(a.z, b.z)),
+        }
+    }
+
+    /// The box from the origin to `size` along each axis
+    pub fn with_size(x: T, y: T, z: T) -> Self {
+        Self::new(Point3::new(T::zero(), T::zero(), T::zero()), Point3::new(x, y, z))
+    }
+
+    /// The smallest box holding every point, `None` if there are none
+    pub fn around(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+        points
+            .into_iter()
+            .map(|point| Self::new(point, point))
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// The corner with the smallest coordinates
+    pub fn min(&self) -> Point3<T> {
+        self.min
+    }
+
+    /// The corner with the largest coordinates
+    pub fn max(&self) -> Point3<T> {
+        self.max
+    }
+
+    /// The length of the box along x, y and z
+    pub fn size(&self) -> [T; 3] {
+        [self.max.x - self.min.x, self.max.y - self.min.y, self.max.z - self.min.z]
+    }
+
+    pub fn centre(&self) -> Point3<f64> {
+        let [min, max] = [self.min.to_f64(), self.max.to_f64()];
+        Point3::new((min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0, (min[2] + max[2]) / 2.0)
+    }
+
+    /// The space inside the box, `None` if it overflows `T`
+    pub fn checked_volume(&self) -> Option<T> {
+        Prism::from(*self).checked_volume()
+    }
+
+    /// Panics if the volume overflows `T`; use `checked_volume` to handle that case
+    pub fn volume(&self) -> T {
+        self.checked_volume().expect("volume overflowed")
+    }
+
+    /// The smallest box holding both boxes
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point3::new(Ops::min(self.min.x, other.min.x), Ops::min(self.min.y, other.min.y), Ops::min(self.min.z, other.min.z)),
+            max: Point3::new(Ops::max(self.max.x, other.max.x), Ops::max(self.max.y, other.max.y), Ops::max(self.max.z, other.max.z)),
+        }
+    }
+
+    /// The region inside both boxes, `None` if they do not meet
+    ///
+    /// Boxes that only touch give a box with no thickness along the touching axis.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Point3::new(Ops::max(self.min.x, other.min.x), Ops::max(self.min.y, other.min.y), Ops::max(self.min.z, other.min.z));
+        let max = Point3::new(Ops::min(self.max.x, other.max.x), Ops::min(self.max.y, other.max.y), Ops::min(self.max.z, other.max.z));
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Self { min, max })
+    }
+
+    /// Whether the boxes meet, including only touching at a face, edge or corner
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Whether a point is inside the box or on its surface
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        self.min.x <= point.x && point.x <= self.max.x
+            && self.min.y <= point.y && point.y <= self.max.y
+            && self.min.z <= point.z && point.z <= self.max.z
+    }
+
+    /// Whether the other box lies completely inside this one
+    pub fn contains(&self, other: &Self) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// The eight corners, starting at `min` and ending at `max`
+    pub fn corners(&self) -> [Point3<T>; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            Point3::new(a.x, a.y, a.z), Point3::new(b.x, a.y, a.z), Point3::new(a.x, b.y, a.z), Point3::new(b.x, b.y, a.z),
+            Point3::new(a.x, a.y, b.z), Point3::new(b.x, a.y, b.z), Point3::new(a.x, b.y, b.z), Point3::new(b.x, b.y, b.z),
+        ]
+    }
+}
+
+impl<T> Display for Aabb<T>
+where T:
+    Scalar
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} to {}]", self.min, self.max)
+    }
+}
+
+/// A Prism becomes the box from the origin to its length, width and height
+impl<T> From<Prism<T>> for Aabb<T>
+where T:
+    Scalar
+{
+    fn from(prism: Prism<T>) -> Self {
+        Self::with_size(prism.length(), prism.width(), prism.height())
+    }
+}
+
+/// A box becomes a Prism of the same size, losing its position
+impl<T> From<Aabb<T>> for Prism<T>
+where T:
+    Scalar
+{
+    fn from(aabb: Aabb<T>) -> Self {
+        let [length, width, height] = aabb.size();
+        Prism::new(length, width, height)
+    }
+}
@@ -0,0 +1,283 @@
+use super::{Placed, Point2, Point3, Vector3};
+use crate::{
+    shapes::{Circle, Cube, Prism, Sphere},
+    utils::Scalar,
+};
+
+/// How two overlapping shapes meet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+    /// How far the shapes overlap; zero when they only touch
+    pub depth: f64,
+    /// Unit vector pointing from the first shape towards the second
+    ///
+    /// Moving the second shape by `normal * depth` pulls them apart.
+    pub normal: Vector3,
+}
+
+/// Overlap queries between positioned shapes
+pub trait Collide<Rhs = Self> {
+    /// How the shapes meet, `None` if there is a gap between them
+    fn contact(&self, other: &Rhs) -> Option<Contact>;
+
+    /// Whether the shapes overlap or touch
+    fn overlaps(&self, other: &Rhs) -> bool {
+        self.contact(other).is_some()
+    }
+}
+
+/// Whether a positioned shape holds a point or another shape completely
+pub trait Contains<Rhs = Self> {
+    /// Points and shapes on the surface count as inside
+    fn contains(&self, other: &Rhs) -> bool;
+}
+
+/// Slack allowed for rounding when checking points against the faces of a turned box
+const EPSILON: f64 = 1e-9;
+
+/// The simple solids that collision checks reduce every shape to
+pub(crate) enum Collider {
+    Ball { centre: Point3<f64>, radius: f64 },
+    /// An oriented box with unit `axes` and the distance from its centre to each face along them
+    Block { centre: Point3<f64>, axes: [Vector3; 3], half: [f64; 3] },
+}
+
+impl Collider {
+    fn block<S>(placed: &Placed<S>, size: [f64; 3]) -> Self {
+        let turn = placed.orientation();
+        Self::Block {
+            centre: placed.position(),
+            axes: [Vector3::X, Vector3::Y, Vector3::Z].map(|axis| turn.rotate(axis)),
+            half: size.map(|side| side * placed.scale_factor() / 2.0),
+        }
+    }
+
+    fn contact(&self, other: &Self) -> Option<Contact> {
+        match (self, other) {
+            (Self::Ball { centre: a, radius: ra }, Self::Ball { centre: b, radius: rb }) => {
+                let gap = *b - *a;
+                let depth = ra + rb - gap.length();
+                // Balls with the same centre can be pushed apart in any direction
+                (depth >= 0.0).then(|| Contact { depth, normal: gap.normalized().unwrap_or(Vector3::Z) })
+            }
+            (Self::Ball { centre, radius }, Self::Block { .. }) => ball_block(*centre, *radius, other),
+            (Self::Block { .. }, Self::Ball { centre, radius }) => {
+                ball_block(*centre, *radius, self).map(|contact| Contact { normal: -contact.normal, ..contact })
+            }
+            (Self::Block { .. }, Self::Block { .. }) => block_block(self, other),
+        }
+    }
+
+    fn contains_point(&self, point: Point3<f64>) -> bool {
+        match self {
+            Self::Ball { centre, radius } => (point - *centre).length() <= *radius,
+            Self::Block { centre, axes, half } => {
+                let offset = point - *centre;
+                (0..3).all(|i| offset.dot(axes[i]).abs() <= half[i] + EPSILON)
+            }
+        }
+    }
+
+    fn contains(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Ball { centre: a, radius: ra }, Self::Ball { centre: b, radius: rb }) => (*b - *a).length() + rb <= *ra,
+            (Self::Block { centre, axes, half }, Self::Ball { centre: ball, radius }) => {
+                let offset = *ball - *centre;
+                (0..3).all(|i| offset.dot(axes[i]).abs() + radius <= half[i] + EPSILON)
+            }
+            // Both solids are convex, so holding every corner of a box means holding the box
+            (_, Self::Block { .. }) => other.corners().into_iter().all(|corner| self.contains_point(corner)),
+        }
+    }
+
+    fn corners(&self) -> Vec<Point3<f64>> {
+        match self {
+            Self::Ball { centre, .. } => vec![*centre],
+            Self::Block { centre, axes, half } => (0..8)
+                .map(|corner| {
+                    let sign = |bit: usize| if corner & (1 << bit) == 0 { -1.0 } else { 1.0 };
+                    *centre + axes[0] * (sign(0) * half[0]) + axes[1] * (sign(1) * half[1]) + axes[2] * (sign(2) * half[2])
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Contact from a ball to a block, found from the point of the block closest to the ball's centre
+fn ball_block(centre: Point3<f64>, radius: f64, block: &Collider) -> Option<Contact> {
+    let Collider::Block { centre: box_centre, axes, half } = block else {
+        unreachable!("ball_block needs a block");
+    };
+    let offset = centre - *box_centre;
+    let local = axes.map(|axis| offset.dot(axis));
+
+    if (0..3).all(|i| local[i].abs() <= half[i]) {
+        // The centre is inside, so push the ball out through the nearest face
+        let i = (0..3)
+            .min_by(|&a, &b| (half[a] - local[a].abs()).total_cmp(&(half[b] - local[b].abs())))
+            .expect("a box has three axes");
+        let outward = if local[i] < 0.0 { -axes[i] } else { axes[i] };
+        return Some(Contact { depth: radius + half[i] - local[i].abs(), normal: -outward });
+    }
+
+    let gap = (0..3).fold(Vector3::default(), |gap, i| {
+        let outside = local[i] - local[i].clamp(-half[i], half[i]);
+        gap + axes[i] * outside
+    });
+    let distance = gap.length();
+    (distance <= radius).then(|| Contact { depth: radius - distance, normal: -gap * (1.0 / distance) })
+}
+
+/// Contact between two turned boxes by the separating axis theorem
+///
+/// Two convex solids are apart exactly when some axis exists along which their shadows do
+/// not overlap. For boxes it is enough to try the three face directions of each and the nine
+/// cross products of those; the axis with the smallest overlap gives the contact.
+fn block_block(a: &Collider, b: &Collider) -> Option<Contact> {
+    let (Collider::Block { centre: ca, axes: aa, half: ha }, Collider::Block { centre: cb, axes: ab, half: hb }) = (a, b) else {
+        unreachable!("block_block needs two blocks");
+    };
+    let gap = *cb - *ca;
+    let mut candidates: Vec<Vector3> = aa.iter().chain(ab.iter()).copied().collect();
+    for x in aa {
+        for y in ab {
+            // Edges that are parallel, or nearly so, give no new axis
+            let cross = x.cross(*y);
+            if cross.length() > EPSILON {
+                candidates.extend(cross.normalized());
+            }
+        }
+    }
+
+    let mut best: Option<Contact> = None;
+    for axis in candidates {
+        let reach = |axes: &[Vector3; 3], half: &[f64; 3]| (0..3).map(|i| half[i] * axes[i].dot(axis).abs()).sum::<f64>();
+        let distance = gap.dot(axis);
+        let depth = reach(aa, ha) + reach(ab, hb) - distance.abs();
+        if depth < -EPSILON {
+            return None;
+        }
+        if best.is_none_or(|contact| depth < contact.depth - EPSILON) {
+            let normal = if distance < 0.0 { -axis } else { axis };
+            best = Some(Contact { depth: depth.max(0.0), normal });
+        }
+    }
+    best
+}
+
+impl<T> Placed<Sphere<T>>
+where T:
+    Scalar
+{
+    pub(crate) fn collider(&self) -> Collider {
+        Collider::Ball { centre: self.position(), radius: self.shape().radius_f64() * self.scale_factor() }
+    }
+}
+
+impl<T> Placed<Cube<T>>
+where T:
+    Scalar
+{
+    pub(crate) fn collider(&self) -> Collider {
+        let side = self.shape().sides().to_f64();
+        Collider::block(self, [side; 3])
+    }
+}
+
+impl<T> Placed<Prism<T>>
+where T:
+    Scalar
+{
+    pub(crate) fn collider(&self) -> Collider {
+        let prism = self.shape();
+        Collider::block(self, [prism.length(), prism.width(), prism.height()].map(|side| side.to_f64()))
+    }
+}
+
+macro_rules! impl_collide {
+    ($($a:ident with $b:ident),*) => {
+        $(
+            impl<T> Collide<Placed<$b<T>>> for Placed<$a<T>>
+            where T:
+                Scalar
+            {
+                fn contact(&self, other: &Placed<$b<T>>) -> Option<Contact> {
+                    self.collider().contact(&other.collider())
+                }
+            }
+
+            impl<T> Contains<Placed<$b<T>>> for Placed<$a<T>>
+            where T:
+                Scalar
+            {
+                fn contains(&self, other: &Placed<$b<T>>) -> bool {
+                    self.collider().contains(&other.collider())
+                }
+            }
+        )*
+    };
+}
+
+impl_collide!(
+    Sphere with Sphere, Sphere with Cube, Sphere with Prism,
+    Cube with Sphere, Cube with Cube, Cube with Prism,
+    Prism with Sphere, Prism with Cube, Prism with Prism
+);
+
+macro_rules! impl_contains_point {
+    ($($shape:ident),*) => {
+        $(
+            impl<T> Contains<Point3<f64>> for Placed<$shape<T>>
+            where T:
+                Scalar
+            {
+                fn contains(&self, point: &Point3<f64>) -> bool {
+                    self.collider().contains_point(*point)
+                }
+            }
+        )*
+    };
+}
+
+impl_contains_point!(Sphere, Cube, Prism);
+
+/// Circles are treated as lying flat in the xy plane at their positions, as in a floor plan,
+/// so the z coordinate and any rotation are ignored.
+impl<T> Collide for Placed<Circle<T>>
+where T:
+    Scalar
+{
+    fn contact(&self, other: &Self) -> Option<Contact> {
+        let (a, b) = (self.position(), other.position());
+        let gap = Vector3::new(b.x - a.x, b.y - a.y, 0.0);
+        let depth = flat_radius(self) + flat_radius(other) - gap.length();
+        (depth >= 0.0).then(|| Contact { depth, normal: gap.normalized().unwrap_or(Vector3::X) })
+    }
+}
+
+impl<T> Contains for Placed<Circle<T>>
+where T:
+    Scalar
+{
+    fn contains(&self, other: &Self) -> bool {
+        let (a, b) = (self.position(), other.position());
+        (b.x - a.x).hypot(b.y - a.y) + flat_radius(other) <= flat_radius(self)
+    }
+}
+
+impl<T> Contains<Point2<f64>> for Placed<Circle<T>>
+where T:
+    Scalar
+{
+    fn contains(&self, point: &Point2<f64>) -> bool {
+        let centre = self.position();
+        (point.x - centre.x).hypot(point.y - centre.y) <= flat_radius(self)
+    }
+}
+
+fn flat_radius<T>(circle: &Placed<Circle<T>>) -> f64
+where T:
+    Scalar
+{
+    circle.shape().radius_f64() * circle.scale_factor()
+}
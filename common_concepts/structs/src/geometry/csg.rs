@@ -0,0 +1,221 @@
+use super::{Aabb, Contains, Placed, Point3};
+use crate::{
+    shapes::{Bounded, Shape3D},
+    utils::Scalar,
+};
+
+/// Voxels along the longest side of a Csg when `volume` has to sample it
+const DEFAULT_RESOLUTION: u32 = 48;
+
+/// A positioned solid that can be combined in a Csg tree
+pub trait Region<T> {
+    /// Whether a point is inside the solid or on its surface
+    fn contains_point(&self, point: Point3<f64>) -> bool;
+
+    /// The box around the solid where it sits in space
+    fn bounds(&self) -> Aabb<f64>;
+
+    /// The box the solid fills completely, `None` unless it is a box lined up with the axes
+    fn as_box(&self) -> Option<Aabb<f64>>;
+}
+
+/// Any placed solid that can answer point queries can be used in a Csg
+impl<T, S> Region<T> for Placed<S>
+where T:
+    Scalar,
+S:
+    Bounded<T> + Shape3D<T>,
+Placed<S>:
+    Contains<Point3<f64>>
+{
+    fn contains_point(&self, point: Point3<f64>) -> bool {
+        self.contains(&point)
+    }
+
+    fn bounds(&self) -> Aabb<f64> {
+        self.bounding_box()
+    }
+
+    /// A solid with the same volume as its bounding box fills it, whatever its shape
+    fn as_box(&self) -> Option<Aabb<f64>> {
+        let bounds = self.bounds();
+        let volume = self.checked_volume()?.to_f64();
+        ((bounds.volume() - volume).abs() <= 1e-9 * bounds.volume()).then_some(bounds)
+    }
+}
+
+/// A volume measurement and how far the true value can be from it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VolumeEstimate {
+    pub volume: f64,
+    /// The largest possible difference from the true volume; zero when the volume is exact
+    pub error: f64,
+}
+
+impl VolumeEstimate {
+    pub fn is_exact(&self) -> bool {
+        self.error == 0.0
+    }
+}
+
+/// A solid built by combining positioned solids, such as a bracket with bolt holes
+pub enum Csg<T> {
+    Solid(Box<dyn Region<T>>),
+    /// Everything inside either part
+    Union(Box<Csg<T>>, Box<Csg<T>>),
+    /// Everything inside the first part but not the second
+    Difference(Box<Csg<T>>, Box<Csg<T>>),
+    /// Everything inside both parts
+    Intersection(Box<Csg<T>>, Box<Csg<T>>),
+}
+
+impl<T> Csg<T> {
+    pub fn solid(region: impl Region<T> + 'static) -> Self {
+        Self::Solid(Box::new(region))
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self::Union(Box::new(self), Box::new(other))
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        Self::Difference(Box::new(self), Box::new(other))
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self::Intersection(Box::new(self), Box::new(other))
+    }
+
+    pub fn contains(&self, point: Point3<f64>) -> bool {
+        match self {
+            Self::Solid(region) => region.contains_point(point),
+            Self::Union(a, b) => a.contains(point) || b.contains(point),
+            Self::Difference(a, b) => a.contains(point) && !b.contains(point),
+            Self::Intersection(a, b) => a.contains(point) && b.contains(point),
+        }
+    }
+
+    /// A box holding the whole solid, `None` when it is certainly empty
+    pub fn bounds(&self) -> Option<Aabb<f64>> {
+        match self {
+            Self::Solid(region) => Some(region.bounds()),
+            Self::Union(a, b) => match (a.bounds(), b.bounds()) {
+                (Some(a), Some(b)) => Some(a.union(&b)),
+                (a, b) => a.or(b),
+            },
+            Self::Difference(a, _) => a.bounds(),
+            Self::Intersection(a, b) => a.bounds()?.intersection(&b.bounds()?),
+        }
+    }
+
+    /// The enclosed volume, exact when every part is a box lined up with the axes
+    pub fn volume(&self) -> VolumeEstimate {
+        self.volume_with(DEFAULT_RESOLUTION)
+    }
+
+    /// The enclosed volume, sampled on a grid with `resolution` voxels along the longest side
+    /// when it cannot be found exactly
+    ///
+    /// Sampling classifies each voxel by its corners: voxels with every corner inside count
+    /// fully, those with some corners inside count half and add their other half to the error.
+    /// The bound assumes no part of the solid is thin enough to slip between the corners.
+    /// The same tree and resolution always give the same answer.
+    ///
+    /// Panics if `resolution` is zero.
+    pub fn volume_with(&self, resolution: u32) -> VolumeEstimate {
+        assert!(resolution > 0, "sampling needs at least one voxel");
+        let Some(bounds) = self.bounds() else {
+            return VolumeEstimate { volume: 0.0, error: 0.0 };
+        };
+        let mut boxes = Vec::new();
+        if self.collect_boxes(&mut boxes) {
+            return VolumeEstimate { volume: self.box_volume(&boxes), error: 0.0 };
+        }
+        self.sampled_volume(bounds, resolution)
+    }
+
+    /// Gathers the box of every part, returning `false` if any part is not a box
+    fn collect_boxes(&self, boxes: &mut Vec<Aabb<f64>>) -> bool {
+        match self {
+            Self::Solid(region) => region.as_box().map(|aabb| boxes.push(aabb)).is_some(),
+            Self::Union(a, b) | Self::Difference(a, b) | Self::Intersection(a, b) => {
+                a.collect_boxes(boxes) && b.collect_boxes(boxes)
+            }
+        }
+    }
+
+    /// Splits space along every face of every box, so each cell is wholly inside or outside
+    /// the solid and can be classified by its centre
+    fn box_volume(&self, boxes: &[Aabb<f64>]) -> f64 {
+        let planes = |axis: usize| {
+            let mut values: Vec<f64> = boxes
+                .iter()
+                .flat_map(|aabb| [aabb.min().to_f64()[axis], aabb.max().to_f64()[axis]])
+                .collect();
+            values.sort_by(f64::total_cmp);
+            values.dedup();
+            values
+        };
+        let (xs, ys, zs) = (planes(0), planes(1), planes(2));
+
+        let mut volume = 0.0;
+        for x in xs.windows(2) {
+            for y in ys.windows(2) {
+                for z in zs.windows(2) {
+                    let centre = Point3::new((x[0] + x[1]) / 2.0, (y[0] + y[1]) / 2.0, (z[0] + z[1]) / 2.0);
+                    if self.contains(centre) {
+                        volume += (x[1] - x[0]) * (y[1] - y[0]) * (z[1] - z[0]);
+                    }
+                }
+            }
+        }
+        volume
+    }
+
+    fn sampled_volume(&self, bounds: Aabb<f64>, resolution: u32) -> VolumeEstimate {
+        let size = bounds.size();
+        let step = size.iter().copied().fold(0.0, f64::max) / resolution as f64;
+        if step == 0.0 {
+            return VolumeEstimate { volume: 0.0, error: 0.0 };
+        }
+        let counts = size.map(|side| ((side / step).ceil() as usize).max(1));
+        let min = bounds.min();
+
+        // Classify every grid corner once, since each is shared by up to eight voxels
+        let [nx, ny, nz] = counts.map(|count| count + 1);
+        let mut inside = vec![false; nx * ny * nz];
+        for i in 0..nx {
+            for j in 0..ny {
+                for k in 0..nz {
+                    let point = Point3::new(min.x + i as f64 * step, min.y + j as f64 * step, min.z + k as f64 * step);
+                    inside[(i * ny + j) * nz + k] = self.contains(point);
+                }
+            }
+        }
+
+        let (mut full, mut mixed) = (0_usize, 0_usize);
+        for i in 0..counts[0] {
+            for j in 0..counts[1] {
+                for k in 0..counts[2] {
+                    let corners = (0..8)
+                        .filter(|corner| {
+                            let (di, dj, dk) = (corner & 1, (corner >> 1) & 1, (corner >> 2) & 1);
+                            inside[((i + di) * ny + j + dj) * nz + k + dk]
+                        })
+                        .count();
+                    match corners {
+                        8 => full += 1,
+                        0 => {}
+                        _ => mixed += 1,
+                    }
+                }
+            }
+        }
+
+        let voxel = step.powi(3);
+        VolumeEstimate {
+            volume: (full as f64 + mixed as f64 / 2.0) * voxel,
+            error: mixed as f64 / 2.0 * voxel,
+        }
+    }
+}
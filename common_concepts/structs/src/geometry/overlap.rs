@@ -0,0 +1,94 @@
+use std::f64::consts::PI;
+
+use super::{Aabb, Placed};
+use crate::{
+    shapes::{Circle, Cube, Prism, Sphere},
+    utils::Scalar,
+};
+
+/// How much two positioned shapes share
+pub trait Overlap<Rhs = Self> {
+    /// The shared area of planar shapes or the shared volume of solids, zero when they are apart
+    fn overlap(&self, other: &Rhs) -> f64;
+}
+
+impl<T> Aabb<T>
+where T:
+    Scalar
+{
+    /// The volume inside both boxes, kept in `T` so whole number boxes give an exact answer
+    pub fn overlap_volume(&self, other: &Self) -> T {
+        self.intersection(other).map_or(T::zero(), |shared| shared.volume())
+    }
+}
+
+/// Area shared by circles of radius `r` and `s` with centres `d` apart
+///
+/// The lens is two circular segments, found from the angle each circle spans across the
+/// chord where the edges cross, less the kite joining the centres to the crossing points.
+pub(crate) fn lens_area(r: f64, s: f64, d: f64) -> f64 {
+    if d >= r + s {
+        return 0.0;
+    }
+    if d <= (r - s).abs() {
+        return PI * r.min(s).powi(2);
+    }
+    let alpha = ((d * d + r * r - s * s) / (2.0 * d * r)).clamp(-1.0, 1.0).acos();
+    let beta = ((d * d + s * s - r * r) / (2.0 * d * s)).clamp(-1.0, 1.0).acos();
+    let kite = ((-d + r + s) * (d + r - s) * (d - r + s) * (d + r + s)).max(0.0).sqrt() / 2.0;
+    r * r * alpha + s * s * beta - kite
+}
+
+/// Volume shared by spheres of radius `r` and `s` with centres `d` apart
+///
+/// The lens is two spherical caps meeting at the plane where the surfaces cross.
+pub(crate) fn lens_volume(r: f64, s: f64, d: f64) -> f64 {
+    if d >= r + s {
+        return 0.0;
+    }
+    if d <= (r - s).abs() {
+        return 4.0 / 3.0 * PI * r.min(s).powi(3);
+    }
+    PI * (r + s - d).powi(2) * (d * d + 2.0 * d * (r + s) - 3.0 * (r - s).powi(2)) / (12.0 * d)
+}
+
+/// Circles are treated as lying flat in the xy plane, as for `Collide`
+impl<T> Overlap for Placed<Circle<T>>
+where T:
+    Scalar
+{
+    fn overlap(&self, other: &Self) -> f64 {
+        let (a, b) = (self.position(), other.position());
+        let radius = |circle: &Self| circle.shape().radius_f64() * circle.scale_factor();
+        lens_area(radius(self), radius(other), (b.x - a.x).hypot(b.y - a.y))
+    }
+}
+
+impl<T> Overlap for Placed<Sphere<T>>
+where T:
+    Scalar
+{
+    fn overlap(&self, other: &Self) -> f64 {
+        let radius = |sphere: &Self| sphere.shape().radius_f64() * sphere.scale_factor();
+        lens_volume(radius(self), radius(other), (other.position() - self.position()).length())
+    }
+}
+
+macro_rules! impl_box_overlap {
+    ($($a:ident with $b:ident),*) => {
+        $(
+            /// Boxes are measured through their bounding boxes, which is exact while both stay
+            /// lined up with the axes and an upper bound once either is turned at an angle.
+            impl<T> Overlap<Placed<$b<T>>> for Placed<$a<T>>
+            where T:
+                Scalar
+            {
+                fn overlap(&self, other: &Placed<$b<T>>) -> f64 {
+                    self.bounding_box().overlap_volume(&other.bounding_box())
+                }
+            }
+        )*
+    };
+}
+
+impl_box_overlap!(Cube with Cube, Cube with Prism, Prism with Cube, Prism with Prism);
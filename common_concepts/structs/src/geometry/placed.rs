@@ -0,0 +1,200 @@
+use super::{Aabb, Point3, Quaternion, Vector3};
+use crate::{
+    shapes::{Bounded, Mesh, Resolution, Shape, Shape2D, Shape3D, Tessellate},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// A shape given a position, orientation and size in space
+///
+/// The shape keeps its own dimensions; `position` is where its centre ends up, `orientation`
+/// turns it about that centre and `scale` grows it evenly in every direction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placed<S> {
+    shape: S,
+    position: Point3<f64>,
+    orientation: Quaternion,
+    scale: f64,
+}
+
+impl<S> Placed<S> {
+    /// Places a shape at the origin, unrotated and at its own size
+    pub fn new(shape: S) -> Self {
+        Self::at(shape, Point3::default())
+    }
+
+    pub fn at(shape: S, position: Point3<f64>) -> Self {
+        Self {
+            shape,
+            position,
+            orientation: Quaternion::IDENTITY,
+            scale: 1.0,
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn into_shape(self) -> S {
+        self.shape
+    }
+
+    pub fn position(&self) -> Point3<f64> {
+        self.position
+    }
+
+    pub fn orientation(&self) -> Quaternion {
+        self.orientation
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn translate(&mut self, offset: Vector3) -> &mut Self {
+        self.position = self.position + offset;
+        self
+    }
+
+    /// Turns the shape about its own centre
+    pub fn rotate(&mut self, rotation: Quaternion) -> &mut Self {
+        self.orientation = rotation * self.orientation;
+        self
+    }
+
+    /// Turns the shape about its own centre by `angle` radians around `axis`
+    ///
+    /// Panics if `axis` is the zero vector.
+    pub fn rotate_axis_angle(&mut self, axis: Vector3, angle: f64) -> &mut Self {
+        self.rotate(Quaternion::from_axis_angle(axis, angle))
+    }
+
+    /// Swings the shape around a fixed point, turning it and moving its centre together
+    pub fn rotate_about(&mut self, pivot: Point3<f64>, rotation: Quaternion) -> &mut Self {
+        self.position = pivot + rotation.rotate(self.position - pivot);
+        self.rotate(rotation)
+    }
+
+    /// Grows or shrinks the shape about its own centre
+    ///
+    /// Panics unless `factor` is a positive finite number.
+    pub fn scale(&mut self, factor: f64) -> &mut Self {
+        assert!(factor.is_finite() && factor > 0.0, "scale factor must be positive, got {factor}");
+        self.scale *= factor;
+        self
+    }
+
+    /// Where a point given relative to the shape's centre ends up in space
+    pub fn to_world(&self, local: Point3<f64>) -> Point3<f64> {
+        self.position + self.orientation.rotate(Vector3::from(local) * self.scale)
+    }
+
+    /// Where a point in space sits relative to the shape's centre and axes
+    pub fn to_local(&self, world: Point3<f64>) -> Point3<f64> {
+        let local = self.orientation.inverse().rotate(world - self.position) * (1.0 / self.scale);
+        Point3::new(local.x, local.y, local.z)
+    }
+
+    /// The box around the shape where it sits in space
+    pub fn bounding_box<T>(&self) -> Aabb<f64>
+    where S:
+        Bounded<T>,
+    T:
+        Scalar
+    {
+        let turned = self.shape.rotated_bounds(self.orientation);
+        Aabb::new(
+            self.position + Vector3::from(turned.min()) * self.scale,
+            self.position + Vector3::from(turned.max()) * self.scale,
+        )
+    }
+}
+
+/// Multiplies a measurement by a power of the scale, leaving it untouched at full size
+///
+/// `value` is the measurement in `T` and `unrounded` the same measurement before rounding,
+/// NaN when the shape only measures in `T` and that overflowed. When neither the measurement
+/// nor the factor was rounded, as for a Rational cube, the product is taken in `T` so it stays
+/// exact; otherwise the unrounded value is scaled and rounded once.
+fn scaled<T>(value: Option<T>, unrounded: f64, factor: f64, policy: Policy) -> Option<T>
+where T:
+    Scalar
+{
+    if factor == 1.0 {
+        return value;
+    }
+    let exact_factor = T::from_f64(factor);
+    let exact = exact_factor.to_f64() == factor;
+    match value {
+        Some(value) if exact && (value.to_f64() == unrounded || unrounded.is_nan()) => {
+            Ops::mul_with(value, exact_factor, policy.overflow)
+        }
+        Some(value) if unrounded.is_nan() => Ops::from_f64_with(value.to_f64() * factor, policy),
+        // The inner shape overflowed and has no measurement of its own to fall back on
+        None if unrounded.is_nan() => None,
+        _ => Ops::from_f64_with(unrounded * factor, policy),
+    }
+}
+
+/// Allow a Placed shape to return its area, which grows with the square of the scale
+impl<T, S> Shape<T> for Placed<S>
+where T:
+    Scalar,
+S:
+    Shape<T>
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        scaled(self.shape.area_with(policy), self.shape.area_f64(), self.scale * self.scale, policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        self.shape.area_f64() * self.scale * self.scale
+    }
+}
+
+/// Allow a Placed planar shape to return its perimeter, which grows with the scale
+impl<T, S> Shape2D<T> for Placed<S>
+where T:
+    Scalar,
+S:
+    Shape2D<T>
+{
+    fn perimeter(&self) -> T {
+        scaled(Some(self.shape.perimeter()), self.shape.perimeter_f64(), self.scale, T::default_policy())
+            .expect("perimeter overflowed")
+    }
+
+    fn perimeter_f64(&self) -> f64 {
+        self.shape.perimeter_f64() * self.scale
+    }
+}
+
+/// Allow a Placed solid to return its volume, which grows with the cube of the scale
+impl<T, S> Shape3D<T> for Placed<S>
+where T:
+    Scalar,
+S:
+    Shape3D<T>
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        scaled(self.shape.volume_with(policy), self.shape.volume_f64(), self.scale.powi(3), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        self.shape.volume_f64() * self.scale.powi(3)
+    }
+}
+
+/// Allow a Placed solid to be turned into triangles where it sits in space
+impl<T, S> Tessellate<T> for Placed<S>
+where T:
+    Scalar,
+S:
+    Tessellate<T>
+{
+    fn tessellate(&self, resolution: Resolution) -> Mesh<f64> {
+        let mesh = self.shape.tessellate(resolution);
+        let vertices = mesh.vertices().iter().map(|&vertex| self.to_world(vertex)).collect();
+        Mesh::new(vertices, mesh.triangles().to_vec())
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::{self, Display};
+
+use crate::utils::{Ops, Scalar};
+
+/// A point on a flat plane
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point2<T>
+where T:
+    Scalar
+{
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T>
+where T:
+    Scalar
+{
+    pub fn new(x: T, y: T) -> Self {
+        Self {
+            x,
+            y,
+        }
+    }
+
+    /// Straight line distance to another point
+    pub fn distance(&self, other: &Self) -> T {
+        Ops::hypot(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+}
+
+impl<T> Display for Point2<T>
+where T:
+    Scalar
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A point in space
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point3<T>
+where T:
+    Scalar
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T>
+where T:
+    Scalar
+{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self {
+            x,
+            y,
+            z,
+        }
+    }
+
+    /// Straight line distance to another point
+    pub fn distance(&self, other: &Self) -> T {
+        let dx = (self.x.to_f64() - other.x.to_f64()).abs();
+        let dy = (self.y.to_f64() - other.y.to_f64()).abs();
+        let dz = (self.z.to_f64() - other.z.to_f64()).abs();
+        T::from_f64(dx.hypot(dy).hypot(dz))
+    }
+
+    pub(crate) fn to_f64(self) -> [f64; 3] {
+        [self.x.to_f64(), self.y.to_f64(), self.z.to_f64()]
+    }
+}
+
+impl<T> Display for Point3<T>
+where T:
+    Scalar
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// The gap between two values, without underflowing unsigned types
+pub(crate) fn abs_diff<T>(a: T, b: T) -> T
+where T:
+    Scalar
+{
+    if a > b { a - b } else { b - a }
+}
@@ -0,0 +1,89 @@
+use std::ops::Mul;
+
+use super::Vector3;
+
+/// A rotation in space, stored as a unit quaternion so rotations can be chained without gimbal lock
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    /// The rotation that leaves everything where it is
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Builds a quaternion from its parts, scaled to unit length so it is a pure rotation
+    ///
+    /// Panics if every part is zero.
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        let length = (w * w + x * x + y * y + z * z).sqrt();
+        assert!(length > 0.0 && length.is_finite(), "a rotation quaternion must not be zero");
+        Self {
+            w: w / length,
+            x: x / length,
+            y: y / length,
+            z: z / length,
+        }
+    }
+
+    /// A turn of `angle` radians about `axis`, counter-clockwise when the axis points at the viewer
+    ///
+    /// Panics if `axis` is the zero vector.
+    pub fn from_axis_angle(axis: Vector3, angle: f64) -> Self {
+        let axis = axis.normalized().expect("a rotation axis must not be zero");
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Self::new(cos, axis.x * sin, axis.y * sin, axis.z * sin)
+    }
+
+    /// The axis and angle in radians of the rotation, with the angle between 0 and 2π
+    pub fn to_axis_angle(&self) -> (Vector3, f64) {
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let angle = 2.0 * axis.length().atan2(self.w);
+        (axis.normalized().unwrap_or(Vector3::Z), angle)
+    }
+
+    /// The rotation that undoes this one
+    pub fn inverse(&self) -> Self {
+        Self {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    pub fn rotate(&self, vector: Vector3) -> Vector3 {
+        // v' = v + 2w(q × v) + 2q × (q × v), with q the vector part
+        let q = Vector3::new(self.x, self.y, self.z);
+        let t = q.cross(vector) * 2.0;
+        vector + t * self.w + q.cross(t)
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// `a * b` rotates by `b` first and then by `a`
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
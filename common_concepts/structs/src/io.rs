@@ -0,0 +1,5 @@
+pub mod stl;
+pub mod obj;
+
+pub use stl::{StlError, StlReport, parse_stl, read_stl, write_stl};
+pub use obj::write_obj;
@@ -0,0 +1,19 @@
+use std::io::Write;
+
+use crate::{shapes::Mesh, utils::Scalar};
+
+/// Writes a Mesh as a Wavefront OBJ model, which keeps shared vertices rather than repeating them
+///
+/// OBJ counts vertices from one, so every face index is one more than in the Mesh.
+pub fn write_obj<T>(mesh: &Mesh<T>, writer: &mut impl Write) -> std::io::Result<()>
+where T:
+    Scalar
+{
+    for vertex in mesh.vertices() {
+        writeln!(writer, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+    }
+    for [a, b, c] in mesh.triangles() {
+        writeln!(writer, "f {} {} {}", a + 1, b + 1, c + 1)?;
+    }
+    Ok(())
+}
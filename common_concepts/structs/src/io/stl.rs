@@ -0,0 +1,281 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt, fs,
+    io::Write,
+    path::Path,
+};
+
+use crate::{
+    geometry::Point3,
+    shapes::{
+        Mesh, Shape, Shape3D, ShapeError,
+        mesh::{cross, dot, sub},
+    },
+    utils::Scalar,
+};
+
+/// Bytes before the facet count in a binary STL file
+const HEADER_LEN: usize = 80;
+/// Bytes in one binary facet: a normal, three corners and an attribute count
+const FACET_LEN: usize = 50;
+
+/// Describes why an STL file could not be turned into a solid
+#[derive(Debug)]
+pub enum StlError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file ended partway through; the expected count is only known for binary files
+    Truncated { facets_read: usize, facets_expected: Option<usize> },
+    /// A line of an ASCII file did not match the format
+    Parse { line: usize, message: String },
+    /// A corner of the facet at this index, counting from zero, has a NaN or infinite coordinate
+    NonFinite { facet: usize },
+    /// The facets do not form a closed, consistently oriented surface
+    Mesh(ShapeError),
+}
+
+impl fmt::Display for StlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read STL file: {err}"),
+            Self::Truncated { facets_read, facets_expected: Some(expected) } => {
+                write!(f, "STL file is truncated after {facets_read} of {expected} facets")
+            }
+            Self::Truncated { facets_read, facets_expected: None } => {
+                write!(f, "STL file is truncated after {facets_read} complete facets")
+            }
+            Self::Parse { line, message } => write!(f, "STL line {line}: {message}"),
+            Self::NonFinite { facet } => write!(f, "STL facet {facet} has a coordinate that is not finite"),
+            Self::Mesh(err) => write!(f, "STL facets do not enclose a solid: {err}"),
+        }
+    }
+}
+
+impl Error for StlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Mesh(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for StlError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ShapeError> for StlError {
+    fn from(err: ShapeError) -> Self {
+        Self::Mesh(err)
+    }
+}
+
+/// A summary of the solid described by an STL file
+#[derive(Clone, Debug, PartialEq)]
+pub struct StlReport {
+    pub facets: usize,
+    /// The corner of the bounding box with the smallest coordinates
+    pub min: Point3<f64>,
+    /// The corner of the bounding box with the largest coordinates
+    pub max: Point3<f64>,
+    pub surface_area: f64,
+    pub volume: f64,
+}
+
+impl StlReport {
+    /// Reads an STL file from disk and measures it
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, StlError> {
+        Ok(Self::from_mesh(&read_stl(path)?))
+    }
+
+    pub fn from_mesh(mesh: &Mesh<f64>) -> Self {
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        for vertex in mesh.vertices() {
+            for (axis, value) in vertex.to_f64().into_iter().enumerate() {
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+            }
+        }
+        Self {
+            facets: mesh.triangles().len(),
+            min: Point3::new(min[0], min[1], min[2]),
+            max: Point3::new(max[0], max[1], max[2]),
+            surface_area: mesh.area(),
+            volume: mesh.volume(),
+        }
+    }
+}
+
+/// Reads an ASCII or binary STL file into a validated Mesh
+pub fn read_stl(path: impl AsRef<Path>) -> Result<Mesh<f64>, StlError> {
+    parse_stl(&fs::read(path)?)
+}
+
+/// Parses the contents of an ASCII or binary STL file into a validated Mesh
+///
+/// Corners shared by neighbouring facets are merged when their coordinates match exactly.
+pub fn parse_stl(bytes: &[u8]) -> Result<Mesh<f64>, StlError> {
+    let facets = if is_binary(bytes) {
+        parse_binary(bytes)?
+    } else {
+        parse_ascii(&String::from_utf8_lossy(bytes))?
+    };
+    Ok(weld(facets)?)
+}
+
+/// Writes a Mesh as an ASCII STL solid called `name`, with a unit normal on every facet
+pub fn write_stl<T>(mesh: &Mesh<T>, name: &str, writer: &mut impl Write) -> std::io::Result<()>
+where T:
+    Scalar
+{
+    writeln!(writer, "solid {name}")?;
+    for triangle in mesh.triangles() {
+        let [a, b, c] = triangle.map(|index| mesh.vertices()[index].to_f64());
+        let normal = cross(sub(b, a), sub(c, a));
+        let length = dot(normal, normal).sqrt();
+        let [nx, ny, nz] = if length > 0.0 { normal.map(|value| value / length) } else { [0.0; 3] };
+
+        writeln!(writer, "  facet normal {nx:e} {ny:e} {nz:e}")?;
+        writeln!(writer, "    outer loop")?;
+        for [x, y, z] in [a, b, c] {
+            writeln!(writer, "      vertex {x:e} {y:e} {z:e}")?;
+        }
+        writeln!(writer, "    endloop")?;
+        writeln!(writer, "  endfacet")?;
+    }
+    writeln!(writer, "endsolid {name}")
+}
+
+/// The number of bytes a binary file with `count` facets takes, `None` if it cannot be addressed
+fn binary_len(count: usize) -> Option<usize> {
+    count.checked_mul(FACET_LEN)?.checked_add(HEADER_LEN + 4)
+}
+
+/// Binary files may also start with `solid`, so trust the size when it matches the facet count
+///
+/// A file that starts with `solid` but holds control bytes is a binary file with a misleading
+/// header, even when its size is wrong, so it is reported as truncated rather than misparsed.
+fn is_binary(bytes: &[u8]) -> bool {
+    if bytes.len() >= HEADER_LEN + 4 {
+        let count = u32::from_le_bytes(bytes[HEADER_LEN..HEADER_LEN + 4].try_into().unwrap()) as usize;
+        if binary_len(count) == Some(bytes.len()) {
+            return true;
+        }
+    }
+    !bytes.trim_ascii_start().starts_with(b"solid")
+        || bytes.iter().any(|&byte| byte.is_ascii_control() && !byte.is_ascii_whitespace())
+}
+
+fn parse_binary(bytes: &[u8]) -> Result<Vec<[[f32; 3]; 3]>, StlError> {
+    if bytes.len() < HEADER_LEN + 4 {
+        return Err(StlError::Truncated { facets_read: 0, facets_expected: None });
+    }
+    let count = u32::from_le_bytes(bytes[HEADER_LEN..HEADER_LEN + 4].try_into().unwrap()) as usize;
+    if binary_len(count).is_none_or(|len| bytes.len() < len) {
+        let facets_read = (bytes.len() - HEADER_LEN - 4) / FACET_LEN;
+        return Err(StlError::Truncated { facets_read, facets_expected: Some(count) });
+    }
+
+    let read_f32 = |chunk: &[u8], offset: usize| f32::from_le_bytes(chunk[offset..offset + 4].try_into().unwrap());
+    bytes[HEADER_LEN + 4..]
+        .chunks_exact(FACET_LEN)
+        .take(count)
+        .enumerate()
+        .map(|(index, facet)| {
+            // Skip the 12 byte normal; it is recomputed from the corners when needed
+            let corners = [0, 1, 2].map(|corner| {
+                let start = 12 + corner * 12;
+                [read_f32(facet, start), read_f32(facet, start + 4), read_f32(facet, start + 8)]
+            });
+            if corners.as_flattened().iter().all(|value| value.is_finite()) {
+                Ok(corners)
+            } else {
+                Err(StlError::NonFinite { facet: index })
+            }
+        })
+        .collect()
+}
+
+fn parse_ascii(text: &str) -> Result<Vec<[[f32; 3]; 3]>, StlError> {
+    let mut facets = Vec::new();
+    let mut corners: Vec<[f32; 3]> = Vec::new();
+    let mut in_facet = false;
+    let mut finished = false;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let mut words = line.split_whitespace();
+        let parse_error = |message: &str| StlError::Parse { line: line_number, message: message.to_string() };
+
+        match words.next() {
+            None | Some("solid") | Some("outer") | Some("endloop") => {}
+            Some("facet") => {
+                if in_facet {
+                    return Err(parse_error("facet started before the previous one ended"));
+                }
+                in_facet = true;
+                corners.clear();
+            }
+            Some("vertex") => {
+                if !in_facet {
+                    return Err(parse_error("vertex outside of a facet"));
+                }
+                let values: Vec<f32> = words
+                    .map(|word| word.parse::<f32>().map_err(|_| parse_error(&format!("invalid number `{word}`"))))
+                    .collect::<Result<_, _>>()?;
+                let corner: [f32; 3] = values
+                    .try_into()
+                    .map_err(|_| parse_error("a vertex needs exactly three coordinates"))?;
+                // Reported like a binary file, since `inf`, `NaN` and values beyond f32 all parse
+                if !corner.iter().all(|value| value.is_finite()) {
+                    return Err(StlError::NonFinite { facet: facets.len() });
+                }
+                corners.push(corner);
+            }
+            Some("endfacet") => {
+                let corner_count = corners.len();
+                let triangle: [[f32; 3]; 3] = std::mem::take(&mut corners)
+                    .try_into()
+                    .map_err(|_| parse_error(&format!("a facet needs 3 vertices, found {corner_count}")))?;
+                facets.push(triangle);
+                in_facet = false;
+            }
+            Some("endsolid") => {
+                // A facet left open means the file was cut short before the end was written
+                finished = !in_facet;
+                break;
+            }
+            Some(word) => return Err(parse_error(&format!("unexpected keyword `{word}`"))),
+        }
+    }
+
+    if !finished {
+        return Err(StlError::Truncated { facets_read: facets.len(), facets_expected: None });
+    }
+    Ok(facets)
+}
+
+/// Merges identical corners into shared vertices so the mesh can be checked for holes
+fn weld(facets: Vec<[[f32; 3]; 3]>) -> Result<Mesh<f64>, ShapeError> {
+    let mut indices: HashMap<[u32; 3], usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    let triangles = facets
+        .into_iter()
+        .map(|facet| {
+            facet.map(|corner| {
+                // Treat -0.0 and 0.0 as the same position
+                let key = corner.map(|value| (value + 0.0).to_bits());
+                *indices.entry(key).or_insert_with(|| {
+                    vertices.push(Point3::new(corner[0] as f64, corner[1] as f64, corner[2] as f64));
+                    vertices.len() - 1
+                })
+            })
+        })
+        .collect();
+    Mesh::try_new(vertices, triangles)
+}
@@ -1,9 +1,12 @@
+pub mod geometry;
+pub mod io;
+pub mod render;
 pub mod shapes;
 pub mod utils;
 
 #[cfg(test)]
 mod tests {
-    use crate::{shapes::{Circle, Cube, Prism, Shape, Sphere}, utils::{FromFloat, Ops}};
+    use crate::{geometry::{Aabb, Collide, Contact, Contains, Csg, Overlap, Placed, Point2, Point3, Quaternion, Vector3}, io::{parse_stl, read_stl, write_obj, write_stl, StlError, StlReport}, render::{Ascii, Dimension, Style, Svg}, shapes::{Bounded, Capsule, Circle, Cone, Cube, Cylinder, Ellipse, Ellipsoid, Frustum, Hollow, Inset, Mesh, Outline, Outlined, Polygon, Projected, Prism, Rectangle, RegularPolygon, Resolution, Shape, Shape2D, Shape3D, ShapeError, Sphere, SphericalCap, SphericalSegment, SphericalShell, Square, SquarePyramid, Tessellate, Torus, Triangle, View, Winding}, utils::{Fixed, FromFloat, Ops, Overflow, PiMultiple, Policy, Rational, Rounding, Scalar, ToFloat}};
     use std::f64::consts::PI;
 
     #[test]
@@ -14,10 +17,10 @@ mod tests {
         assert_eq!(cir.radius(), Ops::half(42.0));
 
         let area = cir.area();
-        let vol = cir.volume();
+        let perimeter = cir.perimeter();
 
-        assert_eq!(vol, 0.0);
-        assert_eq!(area, f32::from_f64(std::f64::consts::PI) * Ops::square(cir.radius()))
+        assert_eq!(area, f32::from_f64(PI * 21.0 * 21.0));
+        assert_eq!(perimeter, f32::from_f64(PI * 42.0))
     }   
 
     #[test]
@@ -28,10 +31,10 @@ mod tests {
         assert_eq!(cir.radius(), 10);
 
         let area = cir.area();
-        let vol = cir.volume();
+        let perimeter = cir.perimeter();
 
-        assert_eq!(vol, 0);
-        assert_eq!(area, i32::from_f64(PI) * Ops::square(cir.radius()))
+        assert_eq!(area, (PI * 100.0).round() as i32);
+        assert_eq!(perimeter, (2.0 * PI * 10.0).round() as i32)
     }
 
     #[test]
@@ -45,9 +48,9 @@ mod tests {
         let vol = s.volume();
         let circumfrence = s.circumference();
 
-        assert_eq!(area, 4 * PI as u64 * Ops::square(s.radius()));
-        assert_eq!(vol, (4 / 3) as u64 * PI as u64 * Ops::cube(s.radius()));
-        assert_eq!(circumfrence, 2 * PI as u64 * s.radius())
+        assert_eq!(area, (4.0 * PI * 400.0).round() as u64);
+        assert_eq!(vol, (4.0 / 3.0 * PI * 8_000.0).round() as u64);
+        assert_eq!(circumfrence, (2.0 * PI * 20.0).round() as u64)
     }
 
     #[test]
@@ -61,9 +64,9 @@ mod tests {
         let vol = s.volume();
         let circumfrence = s.circumference();
 
-        assert_eq!(area, 4 * PI as u64 * Ops::square(s.radius()));
-        assert_eq!(vol, (4 / 3) as u64 * PI as u64 * Ops::cube(s.radius()));
-        assert_eq!(circumfrence, 2 * PI as u64 * s.radius())
+        assert_eq!(area, 1_257);
+        assert_eq!(vol, 4_189);
+        assert_eq!(circumfrence, 63)
     }
 
     #[test]
@@ -86,4 +89,1635 @@ mod tests {
         assert_eq!(cube.area(), 54);
         assert_eq!(cube.volume(), 27);
     }
+
+    #[test]
+    fn test_surface_area_matches_area() {
+        let cube = Cube::new(3);
+        let prism = Prism::new(5, 10, 15);
+        let sphere = Sphere::new_with_radius(2.0_f32);
+
+        assert_eq!(cube.surface_area(), cube.area());
+        assert_eq!(prism.surface_area(), prism.area());
+        assert_eq!(sphere.surface_area(), sphere.area());
+    }
+
+    #[test]
+    fn test_mixed_shapes() {
+        let shapes: Vec<Box<dyn Shape<f32>>> = vec![
+            Box::new(Circle::new_with_radius(1.0)),
+            Box::new(Sphere::new_with_radius(1.0)),
+            Box::new(Cube::new(2.0)),
+            Box::new(Prism::new(1.0, 2.0, 3.0)),
+        ];
+
+        let total: f32 = shapes.iter().map(|s| s.area()).sum();
+        let pi = f32::from_f64(PI);
+
+        assert_eq!(total, pi + 4.0 * pi + 24.0 + 22.0);
+    }
+
+    #[test]
+    fn test_scalar_identities() {
+        assert_eq!(i32::zero(), 0);
+        assert_eq!(u64::one(), 1);
+        assert_eq!(f32::zero(), 0.0);
+        assert_eq!(f32::one(), 1.0);
+    }
+
+    #[test]
+    fn test_custom_scalar_shape() {
+        struct Square<T: Scalar> {
+            side: T,
+        }
+
+        impl<T: Scalar> Shape<T> for Square<T> {
+            fn area_with(&self, policy: Policy) -> Option<T> {
+                Ops::square_with(self.side, policy.overflow)
+            }
+        }
+
+        impl<T: Scalar> Shape2D<T> for Square<T> {
+            fn perimeter(&self) -> T {
+                Ops::double(Ops::double(self.side))
+            }
+        }
+
+        let square = Square { side: 4_u32 };
+
+        assert_eq!(square.area(), 16);
+        assert_eq!(square.checked_area(), Some(16));
+        assert_eq!(square.perimeter(), 16);
+        assert_eq!(Square { side: 70_000_u32 }.checked_area(), None);
+    }
+
+    #[test]
+    fn test_custom_solid_derives_volume() {
+        struct Slab<T: Scalar> {
+            side: T,
+        }
+
+        impl<T: Scalar> Shape<T> for Slab<T> {
+            fn area_with(&self, policy: Policy) -> Option<T> {
+                Ops::double_with(Ops::square_with(self.side, policy.overflow)?, policy.overflow)
+            }
+        }
+
+        impl<T: Scalar> Shape3D<T> for Slab<T> {
+            fn volume_with(&self, policy: Policy) -> Option<T> {
+                Ops::square_with(self.side, policy.overflow)
+            }
+        }
+
+        assert_eq!((Slab { side: 3_i32 }.volume(), Slab { side: 3_i32 }.surface_area()), (9, 18));
+        assert_eq!(Slab { side: 50_000_i32 }.checked_volume(), None);
+        assert_eq!(Slab { side: 50_000_i32 }.volume_with(Overflow::Saturating.into()), Some(i32::MAX));
+        assert_eq!(Slab { side: 1e200_f64 }.volume(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_try_new_accepts_valid_dimensions() {
+        let cir = Circle::try_new_with_radius(3).unwrap();
+        let sphere = Sphere::try_new_with_diameter(8_u64).unwrap();
+        let cube = Cube::try_new(2.5_f32).unwrap();
+        let prism = Prism::try_new(1, 2, 3).unwrap();
+
+        assert_eq!(cir.diameter(), 6);
+        assert_eq!(sphere.radius(), 4);
+        assert_eq!(cube.sides(), 2.5);
+        assert_eq!(prism.volume(), 6);
+    }
+
+    #[test]
+    fn test_try_new_rejects_bad_dimensions() {
+        assert!(matches!(
+            Circle::try_new_with_radius(-1),
+            Err(ShapeError::Negative { dimension: "radius", .. })
+        ));
+        assert!(matches!(
+            Sphere::try_new_with_radius(f32::NAN),
+            Err(ShapeError::NonFinite { dimension: "radius", .. })
+        ));
+        assert!(matches!(
+            Cube::try_new(f32::INFINITY),
+            Err(ShapeError::NonFinite { dimension: "sides", .. })
+        ));
+        assert_eq!(
+            Prism::try_new(1, 0, 3).err(),
+            Some(ShapeError::Degenerate { dimension: "width" })
+        );
+    }
+
+    #[test]
+    fn test_try_new_rejects_collapsed_radius() {
+        assert_eq!(
+            Circle::try_new_with_diameter(1).err(),
+            Some(ShapeError::Degenerate { dimension: "radius" })
+        );
+        assert_eq!(
+            ShapeError::Negative { dimension: "radius", value: -2.0 }.to_string(),
+            "radius must not be negative, got -2"
+        );
+    }
+
+    #[test]
+    fn test_checked_area_and_volume() {
+        let cube = Cube::new(2_000_i32);
+        let sphere = Sphere::new_with_radius(3_000_000_u64);
+
+        assert_eq!(cube.checked_area(), Some(24_000_000));
+        assert_eq!(cube.checked_volume(), None);
+        assert_eq!(sphere.checked_volume(), None);
+        assert_eq!(Cube::new(3_u32).checked_volume(), Some(27));
+    }
+
+    #[test]
+    fn test_overflow_policies() {
+        let cube = Cube::new(2_000_i32);
+        let prism = Prism::new(u32::MAX, 2, 1);
+
+        assert_eq!(cube.volume_with(Overflow::Saturating.into()), Some(i32::MAX));
+        assert_eq!(cube.volume_with(Overflow::Wrapping.into()), Some(2_000_i32.wrapping_mul(2_000).wrapping_mul(2_000)));
+        assert_eq!(prism.volume_with(Overflow::Checked.into()), None);
+        assert_eq!(prism.volume_with(Overflow::Saturating.into()), Some(u32::MAX));
+        assert_eq!(prism.volume_with(Overflow::Wrapping.into()), Some(u32::MAX - 1));
+    }
+
+    #[test]
+    fn test_ops_with_overflow() {
+        assert_eq!(Ops::mul_with(200_u32, 300, Overflow::Checked), Some(60_000));
+        assert_eq!(Ops::add_with(i64::MAX, 1, Overflow::Checked), None);
+        assert_eq!(Ops::sub_with(0_u64, 1, Overflow::Saturating), Some(0));
+        assert_eq!(Ops::square_with(f32::MAX, Overflow::Checked), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "volume overflowed")]
+    fn test_volume_panics_on_overflow() {
+        Cube::new(2_000_i32).volume();
+    }
+
+    #[test]
+    fn test_float_shapes_keep_ieee_results() {
+        assert_eq!(Cube::new(f32::MAX).volume(), f32::INFINITY);
+        assert_eq!(Cube::new(1e200_f64).volume(), f64::INFINITY);
+        assert!(Circle::new_with_radius(f64::NAN).area().is_nan());
+        assert_eq!(Cube::new(1e200_f64).checked_volume(), None);
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let cir = Circle::new_with_radius(10);
+
+        assert_eq!(cir.area_with(Rounding::Nearest.into()), Some(314));
+        assert_eq!(cir.area_with(Rounding::Floor.into()), Some(314));
+        assert_eq!(cir.area_with(Rounding::Ceil.into()), Some(315));
+        assert_eq!(cir.area_with(Rounding::Truncate.into()), Some(314));
+
+        let sphere = Sphere::new_with_radius(1_u32);
+        let policy = Policy::new(Overflow::Checked, Rounding::Ceil);
+
+        assert_eq!(sphere.volume(), 4);
+        assert_eq!(sphere.volume_with(policy), Some(5));
+    }
+
+    #[test]
+    fn test_from_float_rounding() {
+        assert_eq!(i32::from_f64(PI), 3);
+        assert_eq!(i32::from_f64(2.5), 3);
+        assert_eq!(i32::from_f64(-2.5), -3);
+        assert_eq!(i64::from_f64_rounded(-2.5, Rounding::Floor), -3);
+        assert_eq!(i64::from_f64_rounded(-2.5, Rounding::Ceil), -2);
+        assert_eq!(u32::from_f64_rounded(2.9, Rounding::Truncate), 2);
+        assert_eq!(f32::from_f64_rounded(2.5, Rounding::Floor), 2.5);
+    }
+
+    #[test]
+    fn test_from_float_out_of_range() {
+        assert_eq!(i32::checked_from_f64(2_147_483_647.4, Rounding::Nearest), Some(i32::MAX));
+        assert_eq!(i32::checked_from_f64(2_147_483_647.5, Rounding::Nearest), None);
+        assert_eq!(u64::checked_from_f64(-1.0, Rounding::Nearest), None);
+        assert_eq!(i64::checked_from_f64(2_f64.powi(63), Rounding::Nearest), None);
+        assert_eq!(u32::checked_from_f64(f64::NAN, Rounding::Nearest), None);
+        assert_eq!(u32::from_f64(1e12), u32::MAX);
+        assert_eq!(u32::wrapping_from_f64(4_294_967_298.0, Rounding::Nearest), 2);
+        assert_eq!(i32::wrapping_from_f64(-1.0, Rounding::Nearest), -1);
+        assert_eq!(Ops::<u32>::from_f64_with(-3.0, Overflow::Saturating.into()), Some(0));
+    }
+
+    #[test]
+    fn test_wrapping_from_float_for_wide_integers() {
+        assert_eq!(i64::wrapping_from_f64(-1.0, Rounding::Nearest), -1);
+        assert_eq!(u64::wrapping_from_f64(-1.0, Rounding::Nearest), u64::MAX);
+        assert_eq!(u64::wrapping_from_f64(2_f64.powi(64) + 4096.0, Rounding::Nearest), 4096);
+        assert_eq!(i64::wrapping_from_f64(2_f64.powi(63), Rounding::Nearest), i64::MIN);
+        assert_eq!(isize::wrapping_from_f64(-2.6, Rounding::Nearest), -3);
+        assert_eq!(usize::wrapping_from_f64(-3.0, Rounding::Nearest), usize::MAX - 2);
+        assert_eq!(u128::wrapping_from_f64(-1.0, Rounding::Nearest), u128::MAX);
+        assert_eq!(i128::wrapping_from_f64(2_f64.powi(127), Rounding::Nearest), i128::MIN);
+    }
+
+    #[test]
+    fn test_f64_shapes() {
+        let cir = Circle::new_with_radius(2.0_f64);
+        let sphere = Sphere::new_with_diameter(2.0_f64);
+
+        assert_eq!(cir.area(), PI * 4.0);
+        assert_eq!(cir.perimeter(), PI * 4.0);
+        assert_eq!(sphere.volume(), 4.0 / 3.0 * PI);
+        assert_eq!(Prism::new(0.5_f64, 2.0, 3.0).volume(), 3.0);
+    }
+
+    #[test]
+    fn test_every_integer_scalar() {
+        assert_eq!(Cube::new(2_i8).volume(), 8);
+        assert_eq!(Cube::new(2_i16).volume(), 8);
+        assert_eq!(Cube::new(2_i128).volume(), 8);
+        assert_eq!(Cube::new(2_isize).volume(), 8);
+        assert_eq!(Cube::new(2_u8).volume(), 8);
+        assert_eq!(Cube::new(2_u16).volume(), 8);
+        assert_eq!(Cube::new(2_u128).volume(), 8);
+        assert_eq!(Circle::new_with_radius(3_u8).area(), 28);
+        assert_eq!(Circle::new_with_radius(10_u8).checked_area(), None);
+    }
+
+    #[test]
+    fn test_from_float_saturation_and_nan() {
+        assert_eq!(u8::from_f64(300.0), u8::MAX);
+        assert_eq!(u8::from_f64(-5.0), 0);
+        assert_eq!(i8::from_f64(-300.0), i8::MIN);
+        assert_eq!(i16::from_f64(f64::NAN), 0);
+        assert_eq!(u16::from_f64(f64::INFINITY), u16::MAX);
+        assert_eq!(i128::from_f64(1e30), 1_000_000_000_000_000_019_884_624_838_656);
+        assert_eq!(u128::checked_from_f64(2_f64.powi(128), Rounding::Nearest), None);
+        assert_eq!(isize::checked_from_f64(-1.5, Rounding::Floor), Some(-2));
+        assert_eq!(u8::wrapping_from_f64(257.0, Rounding::Nearest), 1);
+        assert_eq!(i8::wrapping_from_f64(128.0, Rounding::Nearest), -128);
+    }
+
+    #[test]
+    fn test_from_float_for_floats() {
+        assert_eq!(f64::from_f64_rounded(2.75, Rounding::Floor), 2.75);
+        assert_eq!(f32::from_f64(1e300), f32::INFINITY);
+        assert!(f64::from_f64(f64::NAN).is_nan());
+        assert_eq!(f32::checked_from_f64(1e300, Rounding::Nearest), None);
+        assert_eq!(f64::checked_from_f64(f64::NAN, Rounding::Nearest), None);
+        assert_eq!(f64::checked_from_f64(1e300, Rounding::Nearest), Some(1e300));
+        assert_eq!(u128::MAX.to_f64(), 2_f64.powi(128));
+        assert_eq!((-7_i8).to_f64(), -7.0);
+    }
+
+    #[test]
+    fn test_rational_normalises() {
+        let r = Rational::new(6_i32, -16);
+
+        assert_eq!(r.numer(), -3);
+        assert_eq!(r.denom(), 8);
+        assert_eq!(r, Rational::new(-3, 8));
+        assert_eq!(r.to_string(), "-3/8");
+        assert_eq!(Rational::new(4_u32, 2).to_string(), "2");
+        assert_eq!(Rational::new(0_i64, -5), Rational::zero());
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        let half = Rational::new(1_i64, 2);
+        let third = Rational::new(1_i64, 3);
+
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert!(third < half);
+        assert_eq!(Ops::half(Rational::new(3_i64, 4)), Rational::new(3, 8));
+    }
+
+    #[test]
+    fn test_rational_float_conversions() {
+        assert_eq!(Rational::<i64>::from_f64(0.375), Rational::new(3, 8));
+        assert_eq!(Rational::<i64>::from_f64(1.0 / 3.0), Rational::new(1, 3));
+        assert_eq!(Rational::<i32>::from_f64(-2.0), Rational::from_integer(-2));
+        assert_eq!(Rational::<u32>::checked_from_f64(-0.5, Rounding::Nearest), None);
+        assert_eq!(Rational::<i8>::checked_from_f64(1_000.0, Rounding::Nearest), None);
+        assert_eq!(Rational::<i8>::from_f64(1_000.0), Rational::from_integer(i8::MAX));
+        assert_eq!(Rational::new(3_i64, 8).to_f64(), 0.375);
+    }
+
+    #[test]
+    fn test_rational_shapes_are_exact() {
+        let eighth = |n| Rational::new(n, 8_i64);
+        let prism = Prism::new(eighth(3), eighth(4), Rational::from_integer(2));
+        let cube = Cube::new(eighth(3));
+
+        assert_eq!(prism.volume(), eighth(3));
+        assert_eq!(prism.area(), Rational::new(31, 8));
+        assert_eq!(cube.volume(), Rational::new(27, 512));
+        assert_eq!(cube.area(), Rational::new(27, 32));
+        assert!(Prism::try_new(eighth(-1), eighth(1), eighth(1)).is_err());
+    }
+
+    #[test]
+    fn test_rational_checked_overflow() {
+        let big = Rational::new(100_i8, 3);
+
+        assert_eq!(big.checked_mul(big), None);
+        assert_eq!(big.checked_add(Rational::new(1, 3)), Some(Rational::new(101, 3)));
+        assert_eq!(Cube::new(big).checked_volume(), None);
+    }
+
+    #[test]
+    fn test_rational_ordering_near_bounds() {
+        let r = |n, d| Rational::new(n, d);
+
+        assert!(r(100_i8, 3) < r(101, 3));
+        assert!(r(i8::MAX, 2) > r(i8::MAX - 1, 2));
+        assert!(r(-127_i8, 126) > r(-126, 125));
+        assert!(r(-100_i8, 3) > r(-101, 3));
+        assert!(r(125_i8, 126) < r(126, 127));
+        assert_eq!(r(i8::MAX, 5).cmp(&r(i8::MAX, 5)), std::cmp::Ordering::Equal);
+        assert!(Rational::new(250_u8, 251) < Rational::new(251, 252));
+    }
+
+    #[test]
+    fn test_circle_exact_results() {
+        let cir = Circle::new_with_radius(10_i32);
+
+        assert_eq!(cir.exact_area().to_string(), "100 π");
+        assert_eq!(cir.exact_perimeter().to_string(), "20 π");
+        assert_eq!(cir.exact_area().evaluate::<f64>(), 100.0 * PI);
+        assert_eq!(cir.exact_area().evaluate::<i32>(), cir.area());
+    }
+
+    #[test]
+    fn test_sphere_exact_results() {
+        let sphere = Sphere::new_with_radius(10_i64);
+        let volume = sphere.exact_volume();
+
+        assert_eq!(sphere.exact_area().to_string(), "400 π");
+        assert_eq!(volume.to_string(), "4000/3 π");
+        assert_eq!((volume.numer(), volume.denom(), volume.power()), (4_000, 3, 1));
+        assert_eq!(volume.evaluate::<i64>(), sphere.volume());
+        assert_eq!(Sphere::new_with_radius(3_u32).exact_volume().to_string(), "36 π");
+        assert_eq!(volume.evaluate_with::<u8>(Policy::default()), None);
+    }
+
+    #[test]
+    fn test_pi_multiple_with_rationals() {
+        let sphere = Sphere::new_with_radius(Rational::new(1_i64, 2));
+        let torus = PiMultiple::new(2_u32, 1, 2);
+
+        assert_eq!(sphere.exact_volume().to_string(), "1/6 π");
+        assert_eq!(sphere.exact_area().to_string(), "π");
+        assert_eq!(PiMultiple::new(5_i32, 2, 0).to_string(), "5/2");
+        assert_eq!(torus.to_string(), "2 π²");
+        assert_eq!(torus.to_f64(), 2.0 * PI * PI);
+    }
+
+    #[test]
+    fn test_pi_multiple_lowest_terms() {
+        let odd = Circle::new_with_diameter(5_i32);
+
+        assert_eq!(PiMultiple::new(6, 4, 1).to_string(), "3/2 π");
+        assert_eq!(PiMultiple::new(3_i32, -6, 1).to_string(), "-1/2 π");
+        assert_eq!(odd.exact_area().to_string(), "25/4 π");
+        assert_eq!(odd.exact_perimeter().to_string(), "5 π");
+        assert_eq!(Sphere::new_with_radius(10.0).exact_volume().to_string(), "4000/3 π");
+        assert_eq!(Sphere::new_with_radius(2.5).exact_volume().to_string(), "125/6 π");
+        assert_eq!(Circle::new_with_radius(1.5_f32).exact_area().to_string(), "9/4 π");
+        assert_eq!(PiMultiple::new(1.1_f64, 0.7, 1).to_string(), "1.1/0.7 π");
+    }
+
+    #[test]
+    fn test_odd_diameters_agree_with_exact_results() {
+        let circle = Circle::new_with_diameter(5_i32);
+        let sphere = Sphere::new_with_diameter(5_i32);
+
+        assert_eq!(circle.area(), circle.exact_area().evaluate::<i32>());
+        assert_eq!((circle.area(), circle.perimeter()), (20, 16));
+        assert_eq!(sphere.exact_area().to_string(), "25 π");
+        assert_eq!(sphere.exact_volume().to_string(), "125/6 π");
+        assert_eq!(sphere.area(), sphere.exact_area().evaluate::<i32>());
+        assert_eq!(sphere.volume(), sphere.exact_volume().evaluate::<i32>());
+
+        let (a, b) = (Placed::new(circle), Placed::at(Circle::new_with_diameter(5_i32), Point3::new(10.0, 0.0, 0.0)));
+        assert_eq!(a.overlap(&b), 0.0);
+        assert!((a.overlap(&a) - 6.25 * PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fixed_display_and_conversions() {
+        assert_eq!(Fixed::<2>::from_raw(150).to_string(), "1.50");
+        assert_eq!(Fixed::<2>::from_f64(-0.5).to_string(), "-0.50");
+        assert_eq!(Fixed::<3>::from_integer(7).to_string(), "7.000");
+        assert_eq!(Fixed::<0>::from_f64(2.5).to_string(), "3");
+        assert_eq!(Fixed::<2>::from_f64_rounded(1.239, Rounding::Truncate).to_string(), "1.23");
+        assert_eq!(Fixed::<2>::from_raw(125).to_f64(), 1.25);
+        assert_eq!(Fixed::<2>::checked_from_f64(f64::NAN, Rounding::Nearest), None);
+    }
+
+    #[test]
+    fn test_fixed_arithmetic() {
+        let a = Fixed::<3>::from_f64(1.25);
+        let b = Fixed::<3>::from_f64(0.333);
+
+        assert_eq!((a + b).to_string(), "1.583");
+        assert_eq!((a - b).to_string(), "0.917");
+        assert_eq!((a * b).to_string(), "0.416");
+        assert_eq!((a / b).to_string(), "3.754");
+        assert_eq!(Ops::half(Fixed::<1>::from_f64(0.5)).to_string(), "0.3");
+        assert_eq!(Fixed::<2>::from_raw(i128::MAX).checked_mul(Fixed::from_integer(2)), None);
+    }
+
+    #[test]
+    fn test_fixed_policies_round_alike() {
+        let a = Fixed::<3>::from_f64(1.25);
+        let b = Fixed::<3>::from_f64(-0.335);
+
+        assert_eq!((a * b).to_string(), "-0.419");
+        for overflow in [Overflow::Checked, Overflow::Saturating, Overflow::Wrapping] {
+            assert_eq!(Ops::mul_with(a, b, overflow), Some(a * b));
+        }
+    }
+
+    #[test]
+    fn test_fixed_shapes() {
+        let prism = Prism::new(Fixed::<3>::from_f64(1.25), Fixed::from_f64(2.5), Fixed::from_f64(3.333));
+        let sphere = Sphere::new_with_radius(Fixed::<3>::from_f64(1.5));
+        let cir = Circle::new_with_diameter(Fixed::<4>::from_integer(2));
+
+        assert_eq!(prism.volume().to_string(), "10.416");
+        assert_eq!(sphere.volume().to_string(), "14.137");
+        assert_eq!(cir.area().to_string(), "3.1416");
+        assert_eq!(Cube::new(Fixed::<2>::from_f64(0.1)).volume().to_string(), "0.00");
+    }
+
+    #[test]
+    fn test_ops_exact_helpers() {
+        assert_eq!(Ops::powi(3_i32, 4), 81);
+        assert_eq!(Ops::powi(2.0_f64, -2), 0.25);
+        assert_eq!(Ops::powi(Rational::new(2_i64, 3), -3), Rational::new(27, 8));
+        assert_eq!(Ops::powi(7_u8, 0), 1);
+        assert_eq!(Ops::abs(-4_i16), 4);
+        assert_eq!(Ops::abs(-0.5_f32), 0.5);
+        assert_eq!(Ops::abs(9_u64), 9);
+        assert_eq!(Ops::min(3_u32, 8), 3);
+        assert_eq!(Ops::max(-1.5_f64, -2.5), -1.5);
+        assert_eq!(Ops::clamp(12_i32, 0, 10), 10);
+        assert_eq!(Ops::clamp(-0.1_f64, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp requires min <= max")]
+    fn test_ops_clamp_rejects_inverted_range() {
+        Ops::clamp(1_i32, 5, 0);
+    }
+
+    #[test]
+    fn test_ops_float_round_trips() {
+        assert_eq!(Ops::sqrt(16_u32), 4);
+        assert_eq!(Ops::sqrt(10_i32), 3);
+        assert_eq!(Ops::sqrt(2.0_f64), 2.0_f64.sqrt());
+        assert_eq!(Ops::hypot(3_i64, 4), 5);
+        assert_eq!(Ops::hypot(1_u8, 1), 1);
+        assert_eq!(Ops::hypot(1.0_f32, 1.0), std::f32::consts::SQRT_2);
+        assert_eq!(Ops::sin(PI / 2.0), 1.0);
+        assert_eq!(Ops::cos(0.0_f64), 1.0);
+        assert_eq!(Ops::cos(3_i32), -1);
+        assert_eq!(Ops::atan2(1.0_f64, 1.0), PI / 4.0);
+        assert_eq!(Ops::atan2(1_i32, 0), 2);
+        assert_eq!(Ops::sqrt(Fixed::<3>::from_integer(2)).to_string(), "1.414");
+    }
+
+    #[test]
+    fn test_ops_with_policy() {
+        let ceil = Policy::from(Rounding::Ceil);
+        let floor = Policy::from(Rounding::Floor);
+
+        assert_eq!(Ops::sqrt_with(10_i32, ceil), Some(4));
+        assert_eq!(Ops::hypot_with(1_u8, 1, ceil), Some(2));
+        assert_eq!(Ops::sin_with(1_i32, ceil), Some(1));
+        assert_eq!(Ops::cos_with(3_i32, Rounding::Truncate.into()), Some(0));
+        assert_eq!(Ops::atan2_with(1_i32, 0, floor), Some(1));
+        assert_eq!(Ops::sqrt_with(Fixed::<2>::from_integer(2), floor).map(|v| v.to_string()), Some("1.41".to_string()));
+        assert_eq!(Ops::powi_with(10_i32, 10, Policy::default()), None);
+        assert_eq!(Ops::powi_with(10_i32, 10, Overflow::Saturating.into()), Some(i32::MAX));
+        assert_eq!(Ops::powi_with(3_u8, 5, Policy::default()), Some(243));
+        assert_eq!(Ops::abs_with(i8::MIN, Policy::default()), None);
+        assert_eq!(Ops::abs_with(i8::MIN, Overflow::Saturating.into()), Some(i8::MAX));
+        assert_eq!(Ops::abs_with(-2.5_f64, Policy::default()), Some(2.5));
+        assert_eq!(Ops::powi_with(0_i32, -1, Policy::default()), None);
+        assert_eq!(Ops::powi_with(2_i32, -1, Policy::default()), Some(1));
+        assert_eq!(Ops::powi_with(2_i32, -1, floor), Some(0));
+        assert_eq!(Ops::powi_with(-1_i32, -3, Policy::default()), Some(-1));
+        assert_eq!(Ops::powi(0.0_f64, -1), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "powi overflowed")]
+    fn test_ops_powi_panics_on_zero_to_negative_power() {
+        Ops::powi(0_u32, -2);
+    }
+
+    #[test]
+    #[should_panic(expected = "abs overflowed")]
+    fn test_ops_abs_panics_on_min() {
+        Ops::abs(i32::MIN);
+    }
+
+    #[test]
+    fn test_rectangle_and_square() {
+        let rect = Rectangle::new(3, 4);
+        let square = Square::new(2.5_f64);
+
+        assert_eq!(rect.area(), 12);
+        assert_eq!(rect.perimeter(), 14);
+        assert_eq!(rect.diagonal(), 5);
+        assert_eq!(square.area(), 6.25);
+        assert_eq!(square.perimeter(), 10.0);
+        assert_eq!(Rectangle::new(u16::MAX, 2).checked_area(), None);
+        assert!(matches!(Square::try_new(-1.0), Err(ShapeError::Negative { dimension: "side", .. })));
+    }
+
+    #[test]
+    fn test_triangle_constructors() {
+        let from_sides = Triangle::new_with_sides(3.0_f64, 4.0, 5.0);
+        let from_base = Triangle::new_with_base_height(3_u32, 4);
+        let from_points = Triangle::new_with_coordinates(
+            Point2::new(0, 0), Point2::new(4, 0), Point2::new(0, 3)
+        );
+
+        assert_eq!(from_sides.area(), 6.0);
+        assert_eq!(from_sides.perimeter(), 12.0);
+        assert_eq!(from_base.sides(), [3, 4, 5]);
+        assert_eq!(from_base.area(), 6);
+        assert_eq!(from_points.sides(), [5, 3, 4]);
+        assert_eq!(from_points.area(), 6);
+
+        let equilateral = Triangle::new_with_sides(2.0_f64, 2.0, 2.0);
+        assert!((equilateral.area() - 3.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_triangle_validation() {
+        assert!(Triangle::try_new_with_sides(3, 4, 5).is_ok());
+        assert!(matches!(Triangle::try_new_with_sides(1, 2, 5), Err(ShapeError::Impossible { .. })));
+        assert_eq!(Triangle::try_new_with_sides(1, 2, 3).err(), Some(ShapeError::Degenerate { dimension: "area" }));
+        assert_eq!(
+            Triangle::try_new_with_coordinates(Point2::new(0, 0), Point2::new(1, 1), Point2::new(2, 2)).err(),
+            Some(ShapeError::Degenerate { dimension: "area" })
+        );
+        assert!(matches!(Triangle::try_new_with_base_height(0.0, 1.0), Err(ShapeError::Degenerate { dimension: "base" })));
+    }
+
+    #[test]
+    fn test_ellipse() {
+        let ellipse = Ellipse::new(2.0_f64, 5.0);
+        let circular = Ellipse::new(3.0_f64, 3.0);
+
+        assert_eq!(ellipse.semi_major(), 5.0);
+        assert_eq!(ellipse.semi_minor(), 2.0);
+        assert_eq!(ellipse.area(), 10.0 * PI);
+        assert_eq!(ellipse.exact_area().to_string(), "10 π");
+        // The exact perimeter of this ellipse is 23.01310...
+        assert!((ellipse.perimeter() - 23.013_1).abs() < 1e-4);
+        assert!((circular.perimeter() - 6.0 * PI).abs() < 1e-12);
+        assert_eq!(circular.eccentricity(), 0.0);
+        assert_eq!(Ellipse::new_with_diameters(10_i32, 4).area(), 31);
+    }
+
+    #[test]
+    fn test_regular_polygon() {
+        let hexagon = RegularPolygon::new(6, 2.0_f64);
+        let square = RegularPolygon::new(4, 3_i32);
+
+        assert!((hexagon.area() - 6.0 * 3.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(hexagon.perimeter(), 12.0);
+        assert!((hexagon.circumradius() - 2.0).abs() < 1e-12);
+        assert!((hexagon.apothem() - 3.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(square.area(), 9);
+        assert_eq!(square.perimeter(), 12);
+        assert!(matches!(RegularPolygon::try_new(2, 1.0), Err(ShapeError::Impossible { .. })));
+    }
+
+    #[test]
+    fn test_point_distance() {
+        assert_eq!(Point2::new(1_u32, 1).distance(&Point2::new(4, 5)), 5);
+        assert_eq!(Point2::new(-1.0_f64, 2.0).distance(&Point2::new(2.0, -2.0)), 5.0);
+        assert_eq!(Point2::new(1, 2).to_string(), "(1, 2)");
+    }
+
+    #[test]
+    fn test_cylinder() {
+        let cylinder = Cylinder::new(2.0_f64, 5.0);
+        let int_cylinder = Cylinder::new_with_diameter(4_i32, 5);
+
+        assert_eq!(cylinder.area(), 28.0 * PI);
+        assert_eq!(cylinder.volume(), 20.0 * PI);
+        assert_eq!(cylinder.lateral_area(), 20.0 * PI);
+        assert_eq!(int_cylinder.radius(), 2);
+        assert_eq!(int_cylinder.area(), 88);
+        assert_eq!(int_cylinder.volume(), 63);
+        assert_eq!(int_cylinder.exact_area().to_string(), "28 π");
+        assert_eq!(int_cylinder.exact_volume().to_string(), "20 π");
+        assert!(matches!(Cylinder::try_new_with_diameter(1, 5), Err(ShapeError::Degenerate { dimension: "radius" })));
+    }
+
+    #[test]
+    fn test_cone() {
+        let cone = Cone::new(3.0_f64, 4.0);
+        let int_cone = Cone::new_with_diameter(6_i64, 4);
+
+        assert_eq!(cone.slant_height(), 5.0);
+        assert_eq!(cone.area(), 24.0 * PI);
+        assert!((cone.volume() - 12.0 * PI).abs() < 1e-12);
+        assert_eq!(cone.lateral_area(), 15.0 * PI);
+        assert_eq!(int_cone.area(), 75);
+        assert_eq!(int_cone.volume(), 38);
+        assert_eq!(int_cone.exact_volume().to_string(), "12 π");
+    }
+
+    #[test]
+    fn test_square_pyramid() {
+        let pyramid = SquarePyramid::new(6_u32, 4);
+        let from_slant = SquarePyramid::new_with_slant_height(6.0_f64, 5.0);
+
+        assert_eq!(pyramid.slant_height(), 5);
+        assert_eq!(pyramid.area(), 96);
+        assert_eq!(pyramid.volume(), 48);
+        assert_eq!(from_slant.height(), 4.0);
+        assert_eq!(from_slant.area(), 96.0);
+        assert_eq!(SquarePyramid::new(1_u32, 1).volume(), 0);
+        assert_eq!(SquarePyramid::new(1_u32, 1).volume_with(Rounding::Ceil.into()), Some(1));
+    }
+
+    #[test]
+    fn test_torus() {
+        let torus = Torus::new(3.0_f64, 1.0);
+        let int_torus = Torus::new_with_diameters(6_i32, 2);
+
+        assert_eq!(torus.area(), 12.0 * PI * PI);
+        assert_eq!(torus.volume(), 6.0 * PI * PI);
+        assert_eq!(int_torus.area(), 118);
+        assert_eq!(int_torus.volume(), 59);
+        assert_eq!(int_torus.exact_volume().to_string(), "6 π²");
+        assert!(matches!(Torus::try_new(1, 3), Err(ShapeError::Impossible { .. })));
+    }
+
+    #[test]
+    fn test_ellipsoid() {
+        let round = Ellipsoid::new(2.0_f64, 2.0, 2.0);
+        let ellipsoid = Ellipsoid::new(3.0_f64, 2.0, 1.0);
+
+        assert!((round.area() - Sphere::new_with_radius(2.0).area()).abs() < 1e-9);
+        assert!((round.volume() - 32.0 / 3.0 * PI).abs() < 1e-12);
+        // The exact surface area is 48.8821...; Thomsen's formula is within about 1%
+        assert!((ellipsoid.area() - 48.882).abs() / 48.882 < 0.01);
+        assert_eq!(Ellipsoid::new_with_diameters(6_u32, 4, 2).volume(), 25);
+        assert_eq!(Ellipsoid::new(3_u32, 2, 1).exact_volume().to_string(), "8 π");
+    }
+
+    #[test]
+    fn test_capsule() {
+        let capsule = Capsule::new(1.0_f64, 2.0);
+        let int_capsule = Capsule::new_with_diameter(2_i32, 2);
+        let ball = Capsule::try_new(1.0_f64, 0.0).unwrap();
+
+        assert_eq!(capsule.total_length(), 4.0);
+        assert_eq!(capsule.area(), 8.0 * PI);
+        assert!((capsule.volume() - 10.0 / 3.0 * PI).abs() < 1e-12);
+        assert_eq!(int_capsule.area(), 25);
+        assert_eq!(int_capsule.volume(), 10);
+        assert_eq!(int_capsule.exact_volume().to_string(), "10/3 π");
+        assert_eq!(ball.volume(), Sphere::new_with_radius(1.0).volume());
+        assert!(Capsule::try_new(1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_frustum() {
+        let frustum = Frustum::new(6.0_f64, 3.0, 4.0);
+        let as_cone = Frustum::new(3.0_f64, 0.0, 4.0);
+
+        assert_eq!(frustum.slant_height(), 5.0);
+        assert_eq!(frustum.curved_area(), 45.0 * PI);
+        assert_eq!(frustum.area(), 90.0 * PI);
+        assert!((frustum.volume() - 84.0 * PI).abs() < 1e-12);
+        assert!((as_cone.volume() - Cone::new(3.0, 4.0).volume()).abs() < 1e-12);
+        assert_eq!(as_cone.area(), Cone::new(3.0, 4.0).area());
+        assert_eq!(Frustum::new_with_diameters(12_u32, 6, 4).volume(), 264);
+        assert!(Frustum::try_new(3.0, 0.0, 4.0).is_ok());
+        assert!(Frustum::try_new(3.0, -1.0, 4.0).is_err());
+    }
+
+    #[test]
+    fn test_spherical_cap() {
+        let half = SphericalCap::new(Sphere::new_with_radius(3.0_f64), 3.0);
+        let cap = SphericalCap::new(Sphere::new_with_radius(5_i32), 2);
+
+        assert!((half.volume() - Sphere::new_with_radius(3.0).volume() / 2.0).abs() < 1e-12);
+        assert_eq!(half.curved_area(), 18.0 * PI);
+        assert_eq!(half.base_radius(), 3.0);
+        assert!((half.area() - 27.0 * PI).abs() < 1e-12);
+        assert_eq!(cap.base_radius(), 4);
+        assert_eq!(cap.volume(), 54);
+        assert_eq!(cap.curved_area(), 63);
+        assert!(SphericalCap::try_new(Sphere::new_with_radius(1.0), 2.5).is_err());
+    }
+
+    #[test]
+    fn test_spherical_segment() {
+        let sphere_volume = Sphere::new_with_radius(2.0_f64).volume();
+        let whole = SphericalSegment::new(Sphere::new_with_radius(2.0_f64), 0.0, 4.0);
+        let middle = SphericalSegment::new(Sphere::new_with_radius(2.0_f64), 3.0, 1.0);
+        let cap = SphericalCap::new(Sphere::new_with_radius(2.0_f64), 1.0);
+
+        assert!((whole.volume() - sphere_volume).abs() < 1e-12);
+        assert_eq!(middle.lower(), 1.0);
+        assert_eq!(middle.height(), 2.0);
+        assert_eq!(middle.curved_area(), 8.0 * PI);
+        assert!((middle.volume() + 2.0 * cap.volume() - sphere_volume).abs() < 1e-12);
+        assert_eq!(middle.base_radii(), (3.0_f64.sqrt(), 3.0_f64.sqrt()));
+        assert!(matches!(
+            SphericalSegment::try_new(Sphere::new_with_radius(2.0), 1.0, 5.0),
+            Err(ShapeError::Impossible { .. })
+        ));
+        assert!(matches!(
+            SphericalSegment::try_new(Sphere::new_with_radius(2.0), 1.0, 1.0),
+            Err(ShapeError::Degenerate { .. })
+        ));
+    }
+
+    #[test]
+    fn test_spherical_shell() {
+        let shell = SphericalShell::new(Sphere::new_with_radius(2.0_f64), 1.0);
+
+        assert_eq!(shell.thickness(), 1.0);
+        assert_eq!(shell.outer_area(), 16.0 * PI);
+        assert_eq!(shell.inner_area(), 4.0 * PI);
+        assert_eq!(shell.area(), 20.0 * PI);
+        assert_eq!(shell.curved_area(), shell.area());
+        assert!((shell.volume() - 28.0 / 3.0 * PI).abs() < 1e-12);
+        assert_eq!(SphericalShell::new(Sphere::new_with_diameter(20_u64), 5).volume(), 3_665);
+        assert!(SphericalShell::try_new(Sphere::new_with_radius(2.0), 2.0).is_err());
+    }
+
+    #[test]
+    fn test_hollow_box() {
+        let tank = Hollow::new(Cube::new(10_u32), 1).unwrap();
+        let crate_box = Hollow::new(Prism::new(10.0_f64, 6.0, 4.0), 0.5).unwrap();
+
+        assert_eq!(tank.inner().sides(), 8);
+        assert_eq!(tank.capacity(), 512);
+        assert_eq!(tank.material_volume(), 488);
+        assert_eq!(tank.volume(), 488);
+        assert_eq!(tank.outer_area(), 600);
+        assert_eq!(tank.inner_area(), 384);
+        assert_eq!(tank.area(), 984);
+        assert_eq!(crate_box.capacity(), 9.0 * 5.0 * 3.0);
+        assert_eq!(crate_box.material_volume(), 240.0 - 135.0);
+    }
+
+    #[test]
+    fn test_hollow_round_solids() {
+        let ball = Hollow::new(Sphere::new_with_radius(2.0_f64), 1.0).unwrap();
+        let shell = SphericalShell::new(Sphere::new_with_radius(2.0_f64), 1.0);
+        let drum = Hollow::new(Cylinder::new(5.0_f64, 12.0), 1.0).unwrap();
+
+        assert_eq!(ball.inner().radius(), 1.0);
+        assert!((ball.material_volume() - shell.volume()).abs() < 1e-12);
+        assert_eq!(ball.area(), shell.area());
+        assert_eq!(drum.inner().radius(), 4.0);
+        assert_eq!(drum.inner().height(), 10.0);
+        assert_eq!(drum.capacity(), 160.0 * PI);
+    }
+
+    #[test]
+    fn test_hollow_rounds_once() {
+        let ball = Hollow::new(Sphere::new_with_radius(3_i32), 1).unwrap();
+        let box_of_thirds = Hollow::new(Cube::new(Rational::new(4_i64, 3)), Rational::new(1, 3)).unwrap();
+
+        assert_eq!((ball.outer().volume(), ball.capacity()), (113, 34));
+        assert_eq!(ball.material_volume(), 80);
+        assert_eq!(ball.volume_with(Rounding::Floor.into()), Some(79));
+        assert_eq!(ball.area(), 163);
+        assert_eq!(box_of_thirds.material_volume(), Rational::new(56, 27));
+    }
+
+    #[test]
+    fn test_hollow_rejects_thick_walls() {
+        assert_eq!(
+            Cube::new(4_i32).inset(2).err(),
+            Some(ShapeError::WallTooThick { thickness: 2.0, limit: 2.0 })
+        );
+        assert!(matches!(
+            Hollow::new(Prism::new(10.0_f64, 6.0, 1.0), 0.6),
+            Err(ShapeError::WallTooThick { .. })
+        ));
+        assert!(matches!(
+            Hollow::new(Cylinder::new(5.0_f64, 1.0), 0.5),
+            Err(ShapeError::WallTooThick { .. })
+        ));
+        assert!(matches!(
+            Hollow::new(Sphere::new_with_radius(1.0_f64), -0.1),
+            Err(ShapeError::Negative { dimension: "thickness", .. })
+        ));
+        assert_eq!(
+            ShapeError::WallTooThick { thickness: 3.0, limit: 2.5 }.to_string(),
+            "wall thickness must be less than 2.5, got 3"
+        );
+    }
+
+    #[test]
+    fn test_polygon_measurements() {
+        let plot = Polygon::new(vec![
+            Point2::new(0, 0), Point2::new(4, 0), Point2::new(4, 3), Point2::new(0, 3),
+        ]);
+        let l_shape = Polygon::try_new(vec![
+            Point2::new(0.0_f64, 0.0), Point2::new(2.0, 0.0), Point2::new(2.0, 1.0),
+            Point2::new(1.0, 1.0), Point2::new(1.0, 2.0), Point2::new(0.0, 2.0),
+        ]).unwrap();
+
+        assert_eq!(plot.area(), 12);
+        assert_eq!(plot.perimeter(), 14);
+        assert_eq!(plot.centroid(), Point2::new(2, 2));
+        assert_eq!(plot.winding(), Winding::CounterClockwise);
+        assert_eq!(l_shape.area(), 3.0);
+        assert_eq!(l_shape.perimeter(), 8.0);
+        assert!((l_shape.centroid().x - 5.0 / 6.0).abs() < 1e-12);
+        assert!((l_shape.centroid().y - 5.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_polygon_winding_and_triangle() {
+        let clockwise = Polygon::new(vec![Point2::new(0.0_f64, 0.0), Point2::new(0.0, 3.0), Point2::new(4.0, 0.0)]);
+        let triangle = Triangle::new_with_sides(3.0_f64, 4.0, 5.0);
+
+        assert_eq!(clockwise.winding(), Winding::Clockwise);
+        assert_eq!(clockwise.area(), triangle.area());
+        assert_eq!(clockwise.perimeter(), triangle.perimeter());
+        assert!(clockwise.is_simple());
+    }
+
+    #[test]
+    fn test_polygon_rejects_non_simple() {
+        let bow_tie = vec![Point2::new(0, 0), Point2::new(2, 2), Point2::new(2, 0), Point2::new(0, 2)];
+        let folded = vec![Point2::new(0, 0), Point2::new(4, 0), Point2::new(2, 0), Point2::new(2, 2)];
+        let pinched = vec![
+            Point2::new(0, 0), Point2::new(2, 0), Point2::new(1, 1), Point2::new(2, 2),
+            Point2::new(0, 2), Point2::new(1, 1),
+        ];
+
+        assert!(!Polygon::new(bow_tie.clone()).is_simple());
+        assert_eq!(
+            Polygon::try_new(bow_tie).err(),
+            Some(ShapeError::SelfIntersecting { first_edge: 0, second_edge: 2 })
+        );
+        assert!(matches!(Polygon::try_new(folded), Err(ShapeError::SelfIntersecting { .. })));
+        assert!(matches!(Polygon::try_new(pinched), Err(ShapeError::SelfIntersecting { .. })));
+        assert!(matches!(
+            Polygon::try_new(vec![Point2::new(0, 0), Point2::new(1, 1)]),
+            Err(ShapeError::Impossible { .. })
+        ));
+    }
+
+    #[test]
+    fn test_polygon_mixes_with_analytic_shapes() {
+        let shapes: Vec<Box<dyn Shape2D<f64>>> = vec![
+            Box::new(Circle::new_with_radius(1.0)),
+            Box::new(Polygon::new(vec![Point2::new(0.0, 0.0), Point2::new(2.0, 0.0), Point2::new(0.0, 2.0)])),
+        ];
+
+        let total: f64 = shapes.iter().map(|s| s.area()).sum();
+        assert_eq!(total, PI + 2.0);
+    }
+
+    fn unit_cube_mesh<T: Scalar>(side: T) -> Mesh<T> {
+        let (o, s) = (T::zero(), side);
+        let vertices = vec![
+            Point3::new(o, o, o), Point3::new(s, o, o), Point3::new(s, s, o), Point3::new(o, s, o),
+            Point3::new(o, o, s), Point3::new(s, o, s), Point3::new(s, s, s), Point3::new(o, s, s),
+        ];
+        let triangles = vec![
+            [0, 2, 1], [0, 3, 2], [4, 5, 6], [4, 6, 7], [0, 1, 5], [0, 5, 4],
+            [3, 7, 6], [3, 6, 2], [0, 4, 7], [0, 7, 3], [1, 2, 6], [1, 6, 5],
+        ];
+        Mesh::new(vertices, triangles)
+    }
+
+    #[test]
+    fn test_mesh_measurements() {
+        let cube = unit_cube_mesh(1.0_f64);
+        let tetrahedron = Mesh::try_new(
+            vec![Point3::new(0.0_f64, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0), Point3::new(0.0, 3.0, 0.0), Point3::new(0.0, 0.0, 3.0)],
+            vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+        ).unwrap();
+
+        assert!(cube.validate().is_ok());
+        assert_eq!(cube.area(), 6.0);
+        assert_eq!(cube.volume(), 1.0);
+        assert_eq!(cube.signed_volume(), 1.0);
+        assert_eq!(tetrahedron.volume(), 4.5);
+        assert!((tetrahedron.area() - (13.5 + 4.5 * 3.0_f64.sqrt())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mesh_inside_out_keeps_positive_volume() {
+        let cube = unit_cube_mesh(2.0_f64);
+        let flipped = Mesh::new(
+            cube.vertices().to_vec(),
+            cube.triangles().iter().map(|&[a, b, c]| [a, c, b]).collect(),
+        );
+
+        assert!(flipped.validate().is_ok());
+        assert_eq!(flipped.signed_volume(), -8.0);
+        assert_eq!(flipped.volume(), 8.0);
+    }
+
+    #[test]
+    fn test_mesh_validation() {
+        let cube = unit_cube_mesh(1_i32);
+        let mut open = cube.triangles().to_vec();
+        open.pop();
+        let mut twisted = cube.triangles().to_vec();
+        twisted[0] = [0, 1, 2];
+        let mut crowded = cube.triangles().to_vec();
+        crowded.push([0, 2, 1]);
+
+        assert_eq!(Mesh::try_new(cube.vertices().to_vec(), open).err(), Some(ShapeError::OpenMesh { from: 5, to: 6 }));
+        assert_eq!(
+            Mesh::try_new(cube.vertices().to_vec(), twisted).err(),
+            Some(ShapeError::InconsistentWinding { from: 0, to: 1 })
+        );
+        assert_eq!(
+            Mesh::try_new(cube.vertices().to_vec(), crowded).err(),
+            Some(ShapeError::NonManifold { from: 0, to: 2 })
+        );
+        assert!(matches!(
+            Mesh::try_new(cube.vertices().to_vec(), vec![[0, 1, 9]]),
+            Err(ShapeError::Impossible { .. })
+        ));
+    }
+
+    #[test]
+    fn test_mesh_mixes_with_analytic_solids() {
+        let solids: Vec<Box<dyn Shape3D<i32>>> = vec![
+            Box::new(unit_cube_mesh(2)),
+            Box::new(Cube::new(2)),
+            Box::new(Prism::new(1, 2, 3)),
+        ];
+
+        let volumes: Vec<i32> = solids.iter().map(|s| s.volume()).collect();
+        let areas: Vec<i32> = solids.iter().map(|s| s.area()).collect();
+
+        assert_eq!(volumes, vec![8, 8, 6]);
+        assert_eq!(areas, vec![24, 24, 22]);
+        assert_eq!(Point3::new(0, 0, 0).distance(&Point3::new(2, 3, 6)), 7);
+    }
+
+    fn binary_stl(mesh: &Mesh<f64>) -> Vec<u8> {
+        let mut bytes = vec![0u8; 80];
+        bytes.extend((mesh.triangles().len() as u32).to_le_bytes());
+        for triangle in mesh.triangles() {
+            bytes.extend([0u8; 12]);
+            for &index in triangle {
+                let v = mesh.vertices()[index];
+                for value in [v.x, v.y, v.z] {
+                    bytes.extend((value as f32).to_le_bytes());
+                }
+            }
+            bytes.extend([0u8; 2]);
+        }
+        bytes
+    }
+
+    fn ascii_stl(mesh: &Mesh<f64>) -> String {
+        let mut text = String::from("solid cube\n");
+        for triangle in mesh.triangles() {
+            text.push_str("  facet normal 0 0 0\n    outer loop\n");
+            for &index in triangle {
+                let v = mesh.vertices()[index];
+                text.push_str(&format!("      vertex {} {} {}\n", v.x, v.y, v.z));
+            }
+            text.push_str("    endloop\n  endfacet\n");
+        }
+        text.push_str("endsolid cube\n");
+        text
+    }
+
+    #[test]
+    fn test_stl_binary_and_ascii_agree() {
+        let cube = unit_cube_mesh(2.0);
+
+        let from_binary = parse_stl(&binary_stl(&cube)).unwrap();
+        let from_ascii = parse_stl(ascii_stl(&cube).as_bytes()).unwrap();
+
+        assert_eq!(from_binary.vertices().len(), 8);
+        assert_eq!(from_binary.triangles().len(), 12);
+        assert_eq!(StlReport::from_mesh(&from_binary), StlReport::from_mesh(&from_ascii));
+    }
+
+    #[test]
+    fn test_stl_report_from_disk() {
+        let path = std::env::temp_dir().join(format!("structs_report_{}.stl", std::process::id()));
+        std::fs::write(&path, binary_stl(&unit_cube_mesh(3.0))).unwrap();
+
+        let report = StlReport::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(report.facets, 12);
+        assert_eq!(report.min, Point3::new(0.0, 0.0, 0.0));
+        assert_eq!(report.max, Point3::new(3.0, 3.0, 3.0));
+        assert!((report.surface_area - 54.0).abs() < 1e-9);
+        assert!((report.volume - 27.0).abs() < 1e-9);
+        assert!(matches!(read_stl(&path), Err(StlError::Io(_))));
+    }
+
+    #[test]
+    fn test_stl_errors() {
+        let cube = unit_cube_mesh(1.0);
+        let binary = binary_stl(&cube);
+        let ascii = ascii_stl(&cube);
+
+        assert!(matches!(
+            parse_stl(&binary[..binary.len() - 60]),
+            Err(StlError::Truncated { facets_read: 10, facets_expected: Some(12) })
+        ));
+        assert!(matches!(
+            parse_stl(&ascii.as_bytes()[..ascii.len() / 2]),
+            Err(StlError::Truncated { facets_expected: None, .. })
+        ));
+        assert!(matches!(
+            parse_stl(ascii.replacen("vertex 0 0 0", "vertex 0 zero 0", 1).as_bytes()),
+            Err(StlError::Parse { line: 4, .. })
+        ));
+
+        let open = Mesh::new(cube.vertices().to_vec(), cube.triangles()[..11].to_vec());
+        let Err(err) = parse_stl(&binary_stl(&open)) else {
+            panic!("an open mesh should be rejected");
+        };
+        assert!(matches!(err, StlError::Mesh(ShapeError::OpenMesh { .. })));
+        assert!(err.to_string().starts_with("STL facets do not enclose a solid"));
+    }
+
+    #[test]
+    fn test_stl_rejects_cut_and_non_finite_files() {
+        let cube = unit_cube_mesh(1.0);
+        let mut binary = binary_stl(&cube);
+        binary[..10].copy_from_slice(b"solid cube");
+        let ascii = ascii_stl(&cube);
+
+        assert!(matches!(
+            parse_stl(&binary[..binary.len() - 60]),
+            Err(StlError::Truncated { facets_read: 10, facets_expected: Some(12) })
+        ));
+
+        let cut = ascii.find("endloop").unwrap();
+        let patched = format!("{}endsolid cube\n", &ascii[..cut]);
+        assert!(matches!(
+            parse_stl(patched.as_bytes()),
+            Err(StlError::Truncated { facets_read: 0, facets_expected: None })
+        ));
+
+        assert!(matches!(
+            parse_stl(ascii.replacen("vertex 0 0 0", "vertex 0 NaN 0", 1).as_bytes()),
+            Err(StlError::NonFinite { facet: 0 })
+        ));
+        assert!(matches!(
+            parse_stl(ascii.replacen("vertex 1 1 1", "vertex 1 1e39 1", 1).as_bytes()),
+            Err(StlError::NonFinite { facet: 2 })
+        ));
+
+        // The first corner of the second facet starts after the header, count, one facet and a normal
+        let start = 84 + 50 + 12;
+        binary[start..start + 4].copy_from_slice(&f32::INFINITY.to_le_bytes());
+        let Err(err) = parse_stl(&binary) else {
+            panic!("an infinite corner should be rejected");
+        };
+        assert!(matches!(err, StlError::NonFinite { facet: 1 }));
+        assert_eq!(err.to_string(), "STL facet 1 has a coordinate that is not finite");
+    }
+
+    #[test]
+    fn test_tessellated_sphere_converges() {
+        let sphere = Sphere::new_with_radius(2.0);
+        let exact = sphere.volume();
+        let uv: Vec<f64> = [(4, 8), (8, 16), (16, 32), (32, 64)]
+            .into_iter()
+            .map(|(rings, segments)| sphere.tessellate(Resolution::Uv { rings, segments }).volume())
+            .collect();
+        let ico: Vec<f64> = (0..5)
+            .map(|subdivisions| sphere.tessellate(Resolution::Icosphere { subdivisions }).volume())
+            .collect();
+
+        for volumes in [&uv, &ico] {
+            assert!(volumes.windows(2).all(|pair| (exact - pair[1]).abs() < (exact - pair[0]).abs()));
+            assert!((exact - volumes[volumes.len() - 1]).abs() / exact < 0.005);
+        }
+        let mesh = sphere.tessellate(Resolution::Icosphere { subdivisions: 2 });
+        assert!(mesh.validate().is_ok());
+        assert!(mesh.signed_volume() > 0.0);
+        assert_eq!((mesh.vertices().len(), mesh.triangles().len()), (162, 320));
+    }
+
+    #[test]
+    fn test_tessellated_solids() {
+        let resolution = Resolution::Uv { rings: 8, segments: 16 };
+        let cube = Cube::new(2).tessellate(resolution);
+        let prism = Prism::new(1.0, 2.0, 3.0).tessellate(resolution);
+        let cylinder = Cylinder::new(1.0, 2.0);
+
+        assert_eq!(cube.volume(), 8.0);
+        assert_eq!(prism.volume(), 6.0);
+        assert_eq!(prism.area(), 22.0);
+        assert!(cylinder.tessellate(resolution).validate().is_ok());
+
+        let coarse = cylinder.tessellate(Resolution::Uv { rings: 1, segments: 8 }).volume();
+        let fine = cylinder.tessellate(Resolution::Icosphere { subdivisions: 5 }).volume();
+        assert!(coarse < fine && fine < cylinder.volume());
+        assert!((cylinder.volume() - fine) / cylinder.volume() < 0.001);
+    }
+
+    #[test]
+    fn test_resolution_clamps_subdivisions() {
+        let finest = Resolution::Icosphere { subdivisions: Resolution::MAX_SUBDIVISIONS };
+
+        assert_eq!(Resolution::Icosphere { subdivisions: 2 }.segments(), 20);
+        assert_eq!(Resolution::Icosphere { subdivisions: 40 }.segments(), finest.segments());
+        assert_eq!(Resolution::Icosphere { subdivisions: u32::MAX }.segments(), 1_280);
+        let drum = Cylinder::new(1.0, 2.0).tessellate(Resolution::Icosphere { subdivisions: 64 });
+        assert_eq!(drum.triangles().len(), 4 * 1_280);
+    }
+
+    #[test]
+    fn test_mesh_export() {
+        let mesh = Sphere::new_with_radius(1.0).tessellate(Resolution::Icosphere { subdivisions: 1 });
+
+        let mut stl = Vec::new();
+        write_stl(&mesh, "ball", &mut stl).unwrap();
+        let round_trip = parse_stl(&stl).unwrap();
+
+        assert!(stl.starts_with(b"solid ball\n"));
+        assert_eq!(round_trip.vertices().len(), mesh.vertices().len());
+        assert_eq!(round_trip.triangles().len(), mesh.triangles().len());
+        assert!((round_trip.volume() - mesh.volume()).abs() < 1e-5);
+
+        let mut obj = Vec::new();
+        write_obj(&unit_cube_mesh(1), &mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+        assert_eq!(obj.lines().filter(|line| line.starts_with("v ")).count(), 8);
+        assert_eq!(obj.lines().nth(8), Some("f 1 3 2"));
+        assert_eq!(obj.lines().count(), 20);
+    }
+
+    #[test]
+    fn test_outlines() {
+        let (min, max) = RegularPolygon::new(4, 2.0).outline().bounds();
+        assert!((min.x + 1.0).abs() < 1e-12 && (min.y + 1.0).abs() < 1e-12);
+        assert!((max.x - 1.0).abs() < 1e-12 && (max.y - 1.0).abs() < 1e-12);
+
+        let Outline::Polygon { vertices } = Triangle::new_with_sides(3.0, 4.0, 5.0).outline() else {
+            panic!("a triangle is outlined by its corners");
+        };
+        let centroid = vertices.iter().fold((0.0, 0.0), |(x, y), v| (x + v.x / 3.0, y + v.y / 3.0));
+        assert!(centroid.0.abs() < 1e-12 && centroid.1.abs() < 1e-12);
+        assert!((vertices[0].distance(&vertices[1]) - 5.0).abs() < 1e-12);
+        assert!((vertices[1].distance(&vertices[2]) - 3.0).abs() < 1e-12);
+        assert_eq!(Circle::new_with_diameter(6).outline(), Outline::Circle { radius: 3.0 });
+    }
+
+    #[test]
+    fn test_svg_circle_with_labels() {
+        let mut svg = Svg::new();
+        svg.add(&Circle::new_with_radius(5), Point2::new(0.0, 0.0), Style::new("navy", "#ddeeff").with_labels(&[Dimension::Radius, Dimension::Diameter]));
+        let text = svg.to_string();
+
+        assert!(text.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-6 -6 12 12">"#));
+        assert!(text.contains(r##"<circle cx="0" cy="0" r="5" stroke="navy" fill="#ddeeff""##));
+        assert!(text.contains(">r = 5</text>"));
+        assert!(text.contains(">d = 10</text>"));
+        assert_eq!(text.matches("<line ").count(), 2);
+        assert!(text.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_svg_collection() {
+        let square = Square::new(2.0);
+        let circle = Circle::new_with_radius(1.0);
+        let triangle = Triangle::new_with_sides(3.0, 4.0, 5.0);
+        let mut svg = Svg::new();
+        svg.add_row(&[&square, &circle, &triangle], 1.0, Style::default().with_labels(&[Dimension::Sides]));
+        svg.add(&Ellipse::new(3, 2), Point2::new(5.5, 4.0), Style::new("red", "none").with_labels(&[Dimension::Radius]));
+        let text = svg.to_string();
+
+        let (min, max) = svg.bounds().unwrap();
+        assert_eq!((min.x, max.x), (0.0, 11.0));
+        assert!((min.y + 1.2).abs() < 1e-12 && max.y == 6.0);
+        assert_eq!(text.matches("<polygon ").count(), 2);
+        assert!(text.contains(r#"<circle cx="4" cy="0" r="1""#));
+        assert!(text.contains(r#"<ellipse cx="5.5" cy="-4" rx="3" ry="2" stroke="red""#));
+        assert_eq!(text.matches(">2</text>").count(), 4);
+        assert!(text.contains(">5</text>") && text.contains(">a = 3</text>") && text.contains(">b = 2</text>"));
+    }
+
+    #[test]
+    fn test_ascii_circle() {
+        let picture = Ascii::new(11).render(&Circle::new_with_radius(5));
+
+        assert_eq!(picture, concat!(
+            "   #####   \n",
+            " ######### \n",
+            "###########\n",
+            "###########\n",
+            " ######### \n",
+            "   #####   \n",
+        ));
+        assert_eq!(Ascii::new(9).with_aspect(1.0).render(&Circle::new_with_radius(1.0)).lines().count(), 9);
+        assert!(Outline::Ellipse { semi_major: 2.0, semi_minor: 1.0 }.contains(Point2::new(1.5, 0.5)));
+        assert!(!Triangle::new_with_base_height(2.0, 2.0).outline().contains(Point2::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_ascii_solid_views() {
+        let prism = Prism::new(6, 2, 3);
+        let top = Ascii::new(12).render_view(&prism, View::Top);
+        let front = Ascii::new(8).with_chars('o', '.').render_view(&Cylinder::new(1.0, 4.0), View::Front);
+        let sphere = Ascii::new(9).render_view(&Sphere::new_with_radius(1.0), View::Side);
+
+        assert_eq!(top, "############\n############\n");
+        assert_eq!(front.lines().count(), 8);
+        assert!(front.lines().all(|line| line == "oooooooo"));
+        assert_eq!(sphere, Ascii::new(9).render(&Circle::new_with_radius(1.0)));
+        assert_eq!(prism.silhouette(View::Side).bounds().1, Point2::new(1.0, 1.5));
+        assert_eq!(Cube::new(2).silhouette(View::Front), Square::new(2).outline());
+    }
+
+    #[test]
+    fn test_ascii_tall_and_flat_outlines() {
+        let needle = Ascii::new(9).render(&Rectangle::new(1.0, 100.0));
+        let line = Polygon::new(vec![Point2::new(0.0, 0.0), Point2::new(0.0, 1.0), Point2::new(0.0, 2.0)]);
+        let point = Polygon::new(vec![Point2::new(1.0, 1.0); 3]);
+
+        assert_eq!(needle.lines().count(), 9);
+        assert!(needle.lines().all(|row| row == "    #    "));
+        assert_eq!(Ascii::new(9).with_max_rows(3).render(&Rectangle::new(1.0, 100.0)).lines().count(), 3);
+        assert_eq!(Ascii::new(4).render(&line).lines().count(), 4);
+        assert_eq!(Ascii::new(4).render(&point), "    \n");
+    }
+
+    fn assert_near(actual: Point3<f64>, expected: Point3<f64>) {
+        assert!(actual.distance(&expected) < 1e-12, "{actual} is not {expected}");
+    }
+
+    #[test]
+    fn test_vectors_and_quaternions() {
+        let quarter = Quaternion::from_axis_angle(Vector3::Z, PI / 2.0);
+        let turned = quarter.rotate(Vector3::X);
+        let half = quarter * quarter;
+
+        assert!((turned - Vector3::Y).length() < 1e-12);
+        assert!((half.rotate(Vector3::X) + Vector3::X).length() < 1e-12);
+        assert!((quarter.inverse().rotate(turned) - Vector3::X).length() < 1e-12);
+        let (axis, angle) = half.to_axis_angle();
+        assert!((axis - Vector3::Z).length() < 1e-12 && (angle - PI).abs() < 1e-12);
+
+        assert_eq!(Vector3::X.cross(Vector3::Y), Vector3::Z);
+        assert_eq!(Vector3::new(3.0, 4.0, 12.0).length(), 13.0);
+        assert_eq!(Vector3::default().normalized(), None);
+        assert_eq!(Point3::new(1.0, 2.0, 3.0) - Point3::new(1.0, 1.0, 1.0), Vector3::new(0.0, 1.0, 2.0));
+        assert_eq!(Quaternion::new(2.0, 0.0, 0.0, 0.0), Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn test_placed_measurements_follow_scale() {
+        let mut cube = Placed::new(Cube::new(2));
+        assert_eq!((cube.volume(), cube.area()), (8, 24));
+        cube.translate(Vector3::new(5.0, 0.0, 0.0)).rotate_axis_angle(Vector3::Y, 1.0);
+        assert_eq!((cube.volume(), cube.area()), (8, 24));
+        cube.scale(2.0);
+        assert_eq!((cube.volume(), cube.area()), (64, 96));
+        assert_eq!(cube.shape().sides(), 2);
+
+        let mut circle = Placed::new(Circle::new_with_radius(1.0));
+        circle.scale(3.0);
+        assert!((circle.area() - 9.0 * PI).abs() < 1e-12);
+        assert!((circle.perimeter() - 6.0 * PI).abs() < 1e-12);
+
+        let mut ball = Placed::at(Sphere::new_with_radius(1.0_f64), Point3::new(0.0, 0.0, 10.0));
+        ball.scale(0.5);
+        assert!((ball.volume() - Sphere::new_with_radius(0.5).volume()).abs() < 1e-12);
+        let mesh = ball.tessellate(Resolution::Icosphere { subdivisions: 3 });
+        assert!((mesh.volume() - ball.volume()).abs() / ball.volume() < 0.01);
+        assert!(mesh.vertices().iter().all(|v| (v.z - 10.0).abs() <= 0.5 + 1e-12));
+    }
+
+    #[test]
+    fn test_placed_scale_rounds_once() {
+        let mut circle = Placed::new(Circle::new_with_radius(1_i32));
+        circle.scale(2.0);
+        assert_eq!((circle.area(), circle.perimeter()), (13, 13));
+
+        let mut sphere = Placed::new(Sphere::new_with_radius(1_u32));
+        sphere.scale(3.0);
+        assert_eq!(sphere.volume(), 113);
+        assert_eq!(sphere.volume_with(Rounding::Floor.into()), Some(113));
+
+        let third = Rational::new(1_i64, 3);
+        let mut cube = Placed::new(Cube::new(third));
+        cube.scale(1.5);
+        assert_eq!((cube.area(), cube.volume()), (Rational::new(3, 2), Rational::new(1, 8)));
+
+        let mut block = Placed::new(Prism::new(Fixed::<2>::from_f64(0.5), Fixed::from_f64(1.5), Fixed::from_integer(2)));
+        block.scale(0.5);
+        assert_eq!(block.volume().to_string(), "0.19");
+    }
+
+    #[test]
+    fn test_placed_shrink_after_overflow() {
+        let mut cube = Placed::new(Cube::new(2_000_i32));
+        cube.scale(0.5);
+
+        assert_eq!(cube.checked_volume(), None);
+        assert!(cube.volume_f64().is_nan());
+
+        let mut ball = Placed::new(Sphere::new_with_radius(2_000_u32));
+        ball.scale(0.5);
+        assert_eq!(ball.shape().checked_volume(), None);
+        assert_eq!(ball.checked_volume(), Some(4_188_790_205));
+    }
+
+    #[test]
+    fn test_placed_transforms() {
+        let mut prism = Placed::at(Prism::new(2, 4, 6), Point3::new(1.0, 1.0, 1.0));
+        prism.rotate(Quaternion::from_axis_angle(Vector3::Z, PI / 2.0));
+
+        let corner = prism.to_world(Point3::new(1.0, 2.0, 3.0));
+        assert_near(corner, Point3::new(-1.0, 2.0, 4.0));
+        assert_near(prism.to_local(corner), Point3::new(1.0, 2.0, 3.0));
+
+        prism.rotate_about(Point3::new(0.0, 0.0, 0.0), Quaternion::from_axis_angle(Vector3::Z, PI));
+        assert_near(prism.position(), Point3::new(-1.0, -1.0, 1.0));
+        assert!((prism.orientation().rotate(Vector3::X) + Vector3::Y).length() < 1e-12);
+        assert_eq!(prism.volume(), 48);
+    }
+
+    #[test]
+    fn test_aabb_operations() {
+        let a = Aabb::new(Point3::new(0, 0, 0), Point3::new(4, 4, 4));
+        let b = Aabb::new(Point3::new(6, 2, 2), Point3::new(2, 6, 6));
+        let far = Aabb::new(Point3::new(10, 10, 10), Point3::new(11, 11, 11));
+        let touching = Aabb::new(Point3::new(4, 0, 0), Point3::new(5, 1, 1));
+
+        assert_eq!(b.min(), Point3::new(2, 2, 2));
+        assert_eq!(a.union(&b), Aabb::new(Point3::new(0, 0, 0), Point3::new(6, 6, 6)));
+        assert_eq!(a.intersection(&b).map(|overlap| overlap.volume()), Some(8));
+        assert_eq!(a.intersection(&touching).map(|overlap| overlap.volume()), Some(0));
+        assert!(a.intersects(&b) && !a.intersects(&far));
+        assert!(a.contains_point(Point3::new(4, 0, 2)) && !a.contains_point(Point3::new(5, 0, 2)));
+        assert!(a.union(&far).contains(&b) && !a.contains(&b));
+        assert_eq!(a.centre(), Point3::new(2.0, 2.0, 2.0));
+        assert_eq!(a.to_string(), "[(0, 0, 0) to (4, 4, 4)]");
+        assert_eq!(Aabb::with_size(200_u8, 200, 200).checked_volume(), None);
+    }
+
+    #[test]
+    fn test_prism_aabb_conversion() {
+        let aabb = Aabb::from(Prism::new(1, 2, 3));
+        let moved = Aabb::new(Point3::new(5, 5, 5), Point3::new(6, 7, 8));
+        let prism = Prism::from(moved);
+
+        assert_eq!(aabb, Aabb::with_size(1, 2, 3));
+        assert_eq!(aabb.size(), moved.size());
+        assert_eq!((prism.length(), prism.width(), prism.height()), (1, 2, 3));
+        assert_eq!(Prism::new(1, 2, 3).bounding_box(), aabb);
+    }
+
+    #[test]
+    fn test_bounding_boxes_of_shapes() {
+        let shapes: Vec<(Box<dyn Bounded<f64>>, [f64; 3])> = vec![
+            (Box::new(Circle::new_with_radius(2.0)), [4.0, 4.0, 0.0]),
+            (Box::new(Ellipse::new(3.0, 2.0)), [6.0, 4.0, 0.0]),
+            (Box::new(Triangle::new_with_sides(3.0, 4.0, 5.0)), [5.0, 2.4, 0.0]),
+            (Box::new(Cube::new(3.0)), [3.0, 3.0, 3.0]),
+            (Box::new(Cylinder::new(1.0, 5.0)), [2.0, 2.0, 5.0]),
+            (Box::new(Torus::new(3.0, 1.0)), [8.0, 8.0, 2.0]),
+            (Box::new(Capsule::new(1.0, 2.0)), [2.0, 2.0, 4.0]),
+            (Box::new(Frustum::new(1.0, 2.0, 3.0)), [4.0, 4.0, 3.0]),
+            (Box::new(SphericalCap::new(Sphere::new_with_radius(2.0), 1.0)), [2.0 * 3.0_f64.sqrt(), 2.0 * 3.0_f64.sqrt(), 1.0]),
+            (Box::new(SphericalSegment::new(Sphere::new_with_radius(2.0), 1.0, 3.0)), [4.0, 4.0, 2.0]),
+            (Box::new(Hollow::new(Sphere::new_with_radius(2.0), 0.5).unwrap()), [4.0, 4.0, 4.0]),
+            (Box::new(unit_cube_mesh(2.0)), [2.0, 2.0, 2.0]),
+        ];
+        for (shape, expected) in &shapes {
+            let size = shape.bounding_box().size();
+            assert!(size.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12), "{size:?} is not {expected:?}");
+            assert_eq!(shape.bounding_box().min(), Point3::new(0.0, 0.0, 0.0));
+        }
+        // The mesh keeps its vertices, everything else is placed about the origin in its own coordinates
+        for (shape, _) in &shapes[..shapes.len() - 1] {
+            assert!(shape.local_bounds().contains_point(Point3::new(0.0, 0.0, 0.0)));
+        }
+        assert_eq!(Cube::new(2.0).local_bounds(), Aabb::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0)));
+        assert_eq!(Cube::new(2.0).bounding_box(), Aabb::with_size(2.0, 2.0, 2.0));
+
+        let polygon = Polygon::new(vec![Point2::new(2, 3), Point2::new(6, 3), Point2::new(4, 9)]);
+        assert_eq!(polygon.bounding_box(), Aabb::new(Point3::new(2, 3, 0), Point3::new(6, 9, 0)));
+        assert_eq!(Triangle::new_with_sides(3, 4, 5).bounding_box(), Aabb::with_size(5, 3, 0));
+        assert_eq!(Sphere::new_with_diameter(4_u32).bounding_box().volume(), 64);
+    }
+
+    #[test]
+    fn test_placed_bounding_boxes() {
+        let mut cube = Placed::at(Cube::new(2), Point3::new(10.0, 0.0, 0.0));
+        cube.rotate_axis_angle(Vector3::Z, PI / 4.0);
+        let turned = cube.bounding_box();
+        assert!((turned.min().x - (10.0 - 2.0_f64.sqrt())).abs() < 1e-12);
+        assert!((turned.max().z - 1.0).abs() < 1e-12);
+
+        let mut ball = Placed::new(Sphere::new_with_radius(1.0));
+        ball.rotate_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7).scale(2.0);
+        assert_eq!(ball.bounding_box(), Aabb::new(Point3::new(-2.0, -2.0, -2.0), Point3::new(2.0, 2.0, 2.0)));
+
+        let mut rod = Placed::new(Cylinder::new(1.0, 4.0));
+        rod.rotate_axis_angle(Vector3::X, PI / 2.0);
+        let size = rod.bounding_box().size();
+        assert!((size[0] - 2.0).abs() < 1e-12 && (size[1] - 4.0).abs() < 1e-12 && (size[2] - 2.0).abs() < 1e-12);
+        assert!(!ball.bounding_box().intersects(&cube.bounding_box()));
+    }
+
+    fn assert_contact(contact: Option<Contact>, depth: f64, normal: Vector3) {
+        let contact = contact.expect("shapes should overlap");
+        assert!((contact.depth - depth).abs() < 1e-9, "depth {} is not {depth}", contact.depth);
+        assert!((contact.normal - normal).length() < 1e-9, "normal {} is not {normal}", contact.normal);
+    }
+
+    #[test]
+    fn test_sphere_contacts() {
+        let small = Placed::new(Sphere::new_with_radius(1.0));
+        let large = Placed::at(Sphere::new_with_radius(2.0), Point3::new(2.5, 0.0, 0.0));
+        let touching = Placed::at(Sphere::new_with_radius(2.0), Point3::new(0.0, 3.0, 0.0));
+        let apart = Placed::at(Sphere::new_with_radius(2.0), Point3::new(0.0, 0.0, 3.5));
+
+        assert_contact(small.contact(&large), 0.5, Vector3::X);
+        assert_contact(large.contact(&small), 0.5, -Vector3::X);
+        assert_contact(small.contact(&touching), 0.0, Vector3::Y);
+        assert!(!small.overlaps(&apart));
+
+        let mut outer = Placed::new(Sphere::new_with_radius(2.5));
+        assert!(!outer.contains(&large));
+        outer.scale(2.0);
+        assert!(outer.contains(&large) && !large.contains(&outer));
+        assert!(small.contains(&Point3::new(0.0, 0.6, 0.8)) && !small.contains(&Point3::new(0.0, 0.7, 0.8)));
+    }
+
+    #[test]
+    fn test_sphere_box_contacts() {
+        let mut cube = Placed::new(Cube::new(2.0));
+        let beside = Placed::at(Sphere::new_with_radius(1.0), Point3::new(1.5, 0.0, 0.0));
+        let inside = Placed::at(Sphere::new_with_radius(0.5), Point3::new(0.8, 0.0, 0.0));
+        let diagonal = Placed::at(Sphere::new_with_radius(1.0), Point3::new(2.0, 2.0, 0.0));
+        let near_corner = Placed::at(Sphere::new_with_radius(0.5), Point3::new(1.8, 0.0, 0.0));
+
+        assert_contact(beside.contact(&cube), 0.5, -Vector3::X);
+        assert_contact(cube.contact(&beside), 0.5, Vector3::X);
+        assert_contact(inside.contact(&cube), 0.7, -Vector3::X);
+        assert!(!diagonal.overlaps(&cube) && !near_corner.overlaps(&cube));
+
+        cube.rotate_axis_angle(Vector3::Z, PI / 4.0);
+        assert_contact(near_corner.contact(&cube), 0.5 - (1.8 - 2.0_f64.sqrt()), -Vector3::X);
+
+        let crate_box = Placed::at(Prism::new(4.0, 4.0, 4.0), Point3::new(0.5, 0.0, 0.0));
+        assert!(crate_box.contains(&beside) && crate_box.contains(&inside) && !crate_box.contains(&diagonal));
+        assert!(Placed::new(Sphere::new_with_radius(2.0)).contains(&cube));
+        assert!(cube.contains(&Point3::new(1.4, 0.0, 0.0)) && !cube.contains(&Point3::new(1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_box_contacts() {
+        let prism = Placed::new(Prism::new(2, 2, 2));
+        let beside = Placed::at(Cube::new(2), Point3::new(1.5, 0.2, 0.0));
+        assert_contact(prism.contact(&beside), 0.5, Vector3::X);
+        assert_contact(beside.contact(&prism), 0.5, -Vector3::X);
+
+        let mut diamond = Placed::new(Cube::new(2));
+        diamond.rotate_axis_angle(Vector3::Z, PI / 4.0);
+        let corner = Placed::at(Cube::new(2), Point3::new(1.9, 1.9, 0.0));
+        let mut tilted = Placed::at(Cube::new(2), Point3::new(2.3, 0.0, 0.0));
+        tilted.rotate_axis_angle(Vector3::Z, PI / 4.0);
+
+        // The bounding boxes overlap but the diamond's sloping face keeps the cubes apart
+        assert!(diamond.bounding_box().intersects(&corner.bounding_box()));
+        assert!(!diamond.overlaps(&corner));
+        assert_contact(prism.contact(&tilted), 2.0_f64.sqrt() + 1.0 - 2.3, Vector3::X);
+
+        let room = Placed::new(Prism::new(10, 10, 10));
+        assert!(room.contains(&tilted) && room.contains(&corner) && !prism.contains(&corner));
+    }
+
+    #[test]
+    fn test_circle_contacts() {
+        let plate = Placed::new(Circle::new_with_radius(2.0));
+        let mut coin = Placed::at(Circle::new_with_radius(1.0), Point3::new(0.0, 2.5, 7.0));
+
+        assert_contact(plate.contact(&coin), 0.5, Vector3::Y);
+        assert!(!plate.contains(&coin));
+        coin.translate(Vector3::new(0.0, -1.5, 0.0));
+        assert!(plate.contains(&coin));
+        assert!(plate.contains(&Point2::new(1.2, 1.6)) && !plate.contains(&Point2::new(1.5, 1.5)));
+    }
+
+    #[test]
+    fn test_circle_lens_area() {
+        let sprinkler = Placed::new(Circle::new_with_radius(1.0));
+        let neighbour = Placed::at(Circle::new_with_radius(1.0), Point3::new(0.6, 0.8, 0.0));
+        let far = Placed::at(Circle::new_with_radius(1.0), Point3::new(3.0, 0.0, 0.0));
+        let mut wide = Placed::new(Circle::new_with_radius(1.0));
+        wide.scale(3.0);
+
+        assert!((sprinkler.overlap(&neighbour) - (2.0 * PI / 3.0 - 3.0_f64.sqrt() / 2.0)).abs() < 1e-12);
+        assert_eq!(sprinkler.overlap(&neighbour), neighbour.overlap(&sprinkler));
+        assert_eq!(sprinkler.overlap(&far), 0.0);
+        assert!((wide.overlap(&neighbour) - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sphere_lens_volume() {
+        let a = Placed::new(Sphere::new_with_radius(1.0));
+        let b = Placed::at(Sphere::new_with_radius(1.0), Point3::new(0.0, 0.0, 1.0));
+        let inner = Placed::at(Sphere::new_with_radius(0.5), Point3::new(0.2, 0.0, 0.0));
+        let big = Placed::at(Sphere::new_with_radius(3.0), Point3::new(3.0, 0.0, 0.0));
+
+        assert!((a.overlap(&b) - 5.0 * PI / 12.0).abs() < 1e-12);
+        assert!((a.overlap(&inner) - inner.volume()).abs() < 1e-12);
+        assert_eq!(a.overlap(&Placed::at(Sphere::new_with_radius(1.0), Point3::new(2.0, 0.0, 0.0))), 0.0);
+
+        // The surfaces cross at x = 1/6, leaving a cap of each sphere
+        let cap = |radius: f64, height: f64| PI * height * height * (3.0 * radius - height) / 3.0;
+        let caps = cap(1.0, 5.0 / 6.0) + cap(3.0, 1.0 / 6.0);
+        assert!((a.overlap(&big) - caps).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_box_overlap_volume() {
+        let cube = Placed::new(Cube::new(2));
+        let mut prism = Placed::at(Prism::new(2, 2, 2), Point3::new(1.0, 1.0, 0.0));
+
+        assert_eq!(cube.overlap(&prism), 2.0);
+        prism.rotate_axis_angle(Vector3::Z, PI / 2.0);
+        assert!((cube.overlap(&prism) - 2.0).abs() < 1e-12);
+        assert_eq!(cube.overlap(&Placed::at(Cube::new(2), Point3::new(5.0, 0.0, 0.0))), 0.0);
+
+        let shelf = Aabb::new(Point3::new(0_u32, 0, 0), Point3::new(10, 4, 3));
+        let bin = Aabb::new(Point3::new(8, 1, 1), Point3::new(12, 5, 5));
+        assert_eq!(shelf.overlap_volume(&bin), 2 * 3 * 2);
+        assert_eq!(bin.overlap_volume(&Aabb::with_size(1, 1, 1)), 0);
+    }
+
+    #[test]
+    fn test_csg_boxes_are_exact() {
+        let bar = || Csg::solid(Placed::new(Prism::new(4, 2, 2)));
+        let block = || Csg::solid(Placed::at(Cube::new(2), Point3::new(1.5, 0.0, 0.0)));
+        let mut turned = Placed::new(Prism::new(4, 2, 2));
+        turned.rotate_axis_angle(Vector3::Z, PI / 2.0);
+
+        let union = bar().union(block()).volume();
+        assert!(union.is_exact());
+        assert_eq!(union.volume, 18.0);
+        assert_eq!(bar().difference(block()).volume().volume, 10.0);
+        assert_eq!(bar().intersection(block()).volume().volume, 6.0);
+        let cross = bar().union(Csg::solid(turned)).volume();
+        assert!(cross.is_exact() && (cross.volume - 24.0).abs() < 1e-12);
+
+        let apart = Csg::solid(Placed::at(Cube::new(1), Point3::new(9.0, 9.0, 9.0)));
+        assert!(bar().intersection(apart).bounds().is_none());
+    }
+
+    #[test]
+    fn test_csg_sampled_volume() {
+        let tank = Csg::solid(Placed::new(Sphere::new_with_radius(2.0)))
+            .difference(Csg::solid(Placed::new(Sphere::new_with_radius(1.0))));
+        let estimate = tank.volume();
+        let exact = 28.0 * PI / 3.0;
+
+        assert!(!estimate.is_exact());
+        assert!((estimate.volume - exact).abs() <= estimate.error);
+        assert!(estimate.error < 0.15 * exact);
+        assert_eq!(tank.volume(), estimate);
+        assert!(tank.contains(Point3::new(0.0, 1.5, 0.0)) && !tank.contains(Point3::new(0.0, 0.5, 0.0)));
+
+        let coarse = tank.volume_with(16);
+        assert!((coarse.volume - exact).abs() <= coarse.error && coarse.error > estimate.error);
+    }
+
+    #[test]
+    fn test_csg_bracket_with_hole() {
+        let mut plate = Placed::new(Prism::new(6.0, 4.0, 1.0));
+        plate.rotate_axis_angle(Vector3::Z, PI / 6.0);
+        let hole = Placed::new(Sphere::new_with_radius(0.5));
+        let bracket = Csg::solid(plate).difference(Csg::solid(hole));
+
+        // The hole fits inside the plate's thickness, so the whole sphere is removed
+        let exact = 24.0 - Sphere::new_with_radius(0.5_f64).volume();
+        let estimate = bracket.volume_with(64);
+        assert!(!estimate.is_exact());
+        assert!((estimate.volume - exact).abs() <= estimate.error);
+        assert!(!bracket.contains(Point3::new(0.2, 0.2, 0.0)) && bracket.contains(Point3::new(2.0, 0.0, 0.0)));
+    }
 }
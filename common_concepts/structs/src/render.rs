@@ -0,0 +1,5 @@
+pub mod svg;
+pub mod ascii;
+
+pub use svg::{Dimension, Style, Svg};
+pub use ascii::Ascii;
@@ -0,0 +1,106 @@
+use crate::{
+    geometry::Point2,
+    shapes::{Outline, Outlined, Projected, View},
+    utils::Scalar,
+};
+
+/// Draws shapes as a grid of characters for terminal output
+///
+/// Terminal cells are usually about twice as tall as they are wide, so by default each row
+/// covers twice the distance of each column and circles come out round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ascii {
+    width: usize,
+    max_rows: usize,
+    aspect: f64,
+    ink: char,
+    blank: char,
+}
+
+impl Ascii {
+    /// A renderer that spreads each shape across `width` columns and at most `width` rows
+    ///
+    /// Panics if `width` is zero.
+    pub fn new(width: usize) -> Self {
+        assert!(width > 0, "an ASCII drawing needs at least one column");
+        Self {
+            width,
+            max_rows: width,
+            aspect: 0.5,
+            ink: '#',
+            blank: ' ',
+        }
+    }
+
+    /// Sets the width of a character cell divided by its height
+    ///
+    /// Panics unless `aspect` is a positive finite number.
+    pub fn with_aspect(mut self, aspect: f64) -> Self {
+        assert!(aspect.is_finite() && aspect > 0.0, "aspect must be positive, got {aspect}");
+        self.aspect = aspect;
+        self
+    }
+
+    /// Limits the number of rows, shrinking tall shapes so they fit
+    ///
+    /// Panics if `max_rows` is zero.
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        assert!(max_rows > 0, "an ASCII drawing needs at least one row");
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Sets the characters used inside and outside the shape
+    pub fn with_chars(mut self, ink: char, blank: char) -> Self {
+        self.ink = ink;
+        self.blank = blank;
+        self
+    }
+
+    pub fn render<T, S>(&self, shape: &S) -> String
+    where S:
+        Outlined<T> + ?Sized,
+    T:
+        Scalar
+    {
+        self.draw(&shape.outline())
+    }
+
+    /// Draws the outline a solid casts when seen from `view`
+    pub fn render_view<T, S>(&self, solid: &S, view: View) -> String
+    where S:
+        Projected<T> + ?Sized,
+    T:
+        Scalar
+    {
+        self.draw(&solid.silhouette(view))
+    }
+
+    /// Fills each cell whose centre falls inside the outline, one line per row from the top down
+    ///
+    /// Shapes fill the width unless that would need more than the row limit, in which case
+    /// the cells grow until the height fits and the shape is centred across the columns.
+    pub fn draw(&self, outline: &Outline) -> String {
+        let (min, max) = outline.bounds();
+        let (span_x, span_y) = (max.x - min.x, max.y - min.y);
+        let cell_width = (span_x / self.width as f64).max(span_y * self.aspect / self.max_rows as f64);
+        // A point or an empty outline has no size to divide up, so any cell size draws it
+        let cell_width = if cell_width > 0.0 && cell_width.is_finite() { cell_width } else { 1.0 };
+        let cell_height = cell_width / self.aspect;
+        let rows = ((span_y / cell_height).round() as usize).clamp(1, self.max_rows);
+        // Centre the grid on the shape when it does not fill a whole number of cells
+        let left = (max.x + min.x) / 2.0 - self.width as f64 * cell_width / 2.0;
+        let top = (max.y + min.y) / 2.0 + rows as f64 * cell_height / 2.0;
+
+        let mut text = String::with_capacity(rows * (self.width + 1));
+        for row in 0..rows {
+            let y = top - (row as f64 + 0.5) * cell_height;
+            for column in 0..self.width {
+                let x = left + (column as f64 + 0.5) * cell_width;
+                text.push(if outline.contains(Point2::new(x, y)) { self.ink } else { self.blank });
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    geometry::Point2,
+    shapes::{Outline, Outlined},
+    utils::Scalar,
+};
+
+/// A measurement that can be marked on a drawn shape
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dimension {
+    /// The radius of a circle, or both semi-axes of an ellipse
+    Radius,
+    /// The diameter of a circle, or both axes of an ellipse
+    Diameter,
+    /// The length of every edge of a polygon
+    Sides,
+}
+
+/// How one shape in a drawing is painted and labelled
+#[derive(Clone, Debug, PartialEq)]
+pub struct Style {
+    /// Any SVG colour, such as `"black"` or `"#336699"`
+    pub stroke: String,
+    /// Any SVG colour, or `"none"` to leave the inside clear
+    pub fill: String,
+    /// Line width in screen pixels, so it does not change with the size of the shapes
+    pub stroke_width: f64,
+    pub labels: Vec<Dimension>,
+}
+
+impl Style {
+    pub fn new(stroke: &str, fill: &str) -> Self {
+        Self {
+            stroke: stroke.to_string(),
+            fill: fill.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// The same style with the given measurements marked on the shape
+    pub fn with_labels(mut self, labels: &[Dimension]) -> Self {
+        self.labels = labels.to_vec();
+        self
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            stroke: "black".to_string(),
+            fill: "none".to_string(),
+            stroke_width: 1.0,
+            labels: Vec::new(),
+        }
+    }
+}
+
+struct Item {
+    outline: Outline,
+    at: Point2<f64>,
+    style: Style,
+}
+
+/// A diagram of planar shapes, written out as an SVG document through `Display`
+///
+/// The viewBox is fitted around every shape with a margin, so callers only choose where
+/// the shapes sit relative to each other.
+#[derive(Default)]
+pub struct Svg {
+    items: Vec<Item>,
+}
+
+impl Svg {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draws a shape with its own origin moved to `at`
+    pub fn add<T, S>(&mut self, shape: &S, at: Point2<f64>, style: Style) -> &mut Self
+    where S:
+        Outlined<T> + ?Sized,
+    T:
+        Scalar
+    {
+        self.items.push(Item {
+            outline: shape.outline(),
+            at,
+            style,
+        });
+        self
+    }
+
+    /// Draws shapes side by side from left to right, `gap` apart and centred on the x axis
+    pub fn add_row<T>(&mut self, shapes: &[&dyn Outlined<T>], gap: f64, style: Style) -> &mut Self
+    where T:
+        Scalar
+    {
+        let mut left = self.bounds().map_or(0.0, |(_, max)| max.x + gap);
+        for shape in shapes {
+            let (min, max) = shape.outline().bounds();
+            let at = Point2::new(left - min.x, -(min.y + max.y) / 2.0);
+            self.add(*shape, at, style.clone());
+            left += max.x - min.x + gap;
+        }
+        self
+    }
+
+    /// The corners of the box around every shape drawn so far, `None` for an empty drawing
+    pub fn bounds(&self) -> Option<(Point2<f64>, Point2<f64>)> {
+        self.items
+            .iter()
+            .map(|item| {
+                let (min, max) = item.outline.bounds();
+                (Point2::new(min.x + item.at.x, min.y + item.at.y), Point2::new(max.x + item.at.x, max.y + item.at.y))
+            })
+            .reduce(|(a_min, a_max), (b_min, b_max)| {
+                (
+                    Point2::new(a_min.x.min(b_min.x), a_min.y.min(b_min.y)),
+                    Point2::new(a_max.x.max(b_max.x), a_max.y.max(b_max.y)),
+                )
+            })
+    }
+
+    /// The visible region as `[x, y, width, height]` in SVG coordinates, where y points down
+    pub fn view_box(&self) -> [f64; 4] {
+        let (min, max) = self.bounds().unwrap_or((Point2::new(0.0, 0.0), Point2::new(1.0, 1.0)));
+        let margin = self.scale() / 10.0;
+        [
+            min.x - margin,
+            -max.y - margin,
+            max.x - min.x + 2.0 * margin,
+            max.y - min.y + 2.0 * margin,
+        ]
+    }
+
+    /// The size of the drawing, used to keep text and margins in proportion to the shapes
+    fn scale(&self) -> f64 {
+        self.bounds().map_or(1.0, |(min, max)| (max.x - min.x).max(max.y - min.y).max(f64::MIN_POSITIVE))
+    }
+
+    fn write_item(&self, f: &mut fmt::Formatter<'_>, item: &Item) -> fmt::Result {
+        let style = &item.style;
+        let paint = format!(
+            r#"stroke="{}" fill="{}" stroke-width="{}" vector-effect="non-scaling-stroke""#,
+            escape(&style.stroke),
+            escape(&style.fill),
+            number(style.stroke_width),
+        );
+        let (x, y) = (item.at.x, item.at.y);
+
+        match &item.outline {
+            Outline::Circle { radius } => {
+                writeln!(f, r#"  <circle cx="{}" cy="{}" r="{}" {paint}/>"#, number(x), number(-y), number(*radius))?;
+                for label in &style.labels {
+                    let diagonal = radius / 2.0_f64.sqrt();
+                    match label {
+                        Dimension::Radius => self.write_measure(f, style, [x, y], [x + diagonal, y + diagonal], "r", *radius)?,
+                        Dimension::Diameter => self.write_measure(f, style, [x - radius, y], [x + radius, y], "d", 2.0 * radius)?,
+                        Dimension::Sides => {}
+                    }
+                }
+            }
+            Outline::Ellipse { semi_major, semi_minor } => {
+                writeln!(
+                    f,
+                    r#"  <ellipse cx="{}" cy="{}" rx="{}" ry="{}" {paint}/>"#,
+                    number(x),
+                    number(-y),
+                    number(*semi_major),
+                    number(*semi_minor),
+                )?;
+                for label in &style.labels {
+                    let (a, b) = (*semi_major, *semi_minor);
+                    match label {
+                        Dimension::Radius => {
+                            self.write_measure(f, style, [x, y], [x + a, y], "a", a)?;
+                            self.write_measure(f, style, [x, y], [x, y + b], "b", b)?;
+                        }
+                        Dimension::Diameter => {
+                            self.write_measure(f, style, [x - a, y], [x + a, y], "2a", 2.0 * a)?;
+                            self.write_measure(f, style, [x, y - b], [x, y + b], "2b", 2.0 * b)?;
+                        }
+                        Dimension::Sides => {}
+                    }
+                }
+            }
+            Outline::Polygon { vertices } => {
+                let points: Vec<String> = vertices
+                    .iter()
+                    .map(|v| format!("{},{}", number(v.x + x), number(-(v.y + y))))
+                    .collect();
+                writeln!(f, r#"  <polygon points="{}" {paint}/>"#, points.join(" "))?;
+                if style.labels.contains(&Dimension::Sides) {
+                    for (index, a) in vertices.iter().enumerate() {
+                        let b = vertices[(index + 1) % vertices.len()];
+                        let length = a.distance(&b);
+                        self.write_text(f, style, [(a.x + b.x) / 2.0 + x, (a.y + b.y) / 2.0 + y], &number(length))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// A dashed line between two points, with `name = value` written at its middle
+    fn write_measure(&self, f: &mut fmt::Formatter<'_>, style: &Style, from: [f64; 2], to: [f64; 2], name: &str, value: f64) -> fmt::Result {
+        writeln!(
+            f,
+            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-dasharray="4 2" vector-effect="non-scaling-stroke"/>"#,
+            number(from[0]),
+            number(-from[1]),
+            number(to[0]),
+            number(-to[1]),
+            escape(&style.stroke),
+        )?;
+        self.write_text(f, style, [(from[0] + to[0]) / 2.0, (from[1] + to[1]) / 2.0], &format!("{name} = {}", number(value)))
+    }
+
+    fn write_text(&self, f: &mut fmt::Formatter<'_>, style: &Style, at: [f64; 2], text: &str) -> fmt::Result {
+        writeln!(
+            f,
+            r#"  <text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="middle" dominant-baseline="text-after-edge">{}</text>"#,
+            number(at[0]),
+            number(-at[1]),
+            number(self.scale() / 20.0),
+            escape(&style.stroke),
+            escape(text),
+        )
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let view_box: Vec<String> = self.view_box().into_iter().map(number).collect();
+        writeln!(f, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{}">"#, view_box.join(" "))?;
+        for item in &self.items {
+            self.write_item(f, item)?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+/// Rounds to three decimal places and drops trailing zeros, so `2.5000001` is written as `2.5`
+fn number(value: f64) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    // Adding zero turns -0 into 0
+    format!("{}", rounded + 0.0)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
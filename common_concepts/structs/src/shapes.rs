@@ -2,13 +2,126 @@ pub mod circle;
 pub mod sphere;
 pub mod cube;
 pub mod prism;
+pub mod rectangle;
+pub mod square;
+pub mod triangle;
+pub mod ellipse;
+pub mod regular_polygon;
+pub mod polygon;
+pub mod cylinder;
+pub mod cone;
+pub mod square_pyramid;
+pub mod torus;
+pub mod ellipsoid;
+pub mod capsule;
+pub mod frustum;
+pub mod spherical_cap;
+pub mod spherical_segment;
+pub mod spherical_shell;
+pub mod hollow;
+pub mod mesh;
+pub mod bounded;
+pub mod outline;
+pub mod tessellate;
+pub mod error;
 
 pub use circle::Circle;
 pub use sphere::Sphere;
 pub use cube::Cube;
 pub use prism::Prism;
+pub use rectangle::Rectangle;
+pub use square::Square;
+pub use triangle::Triangle;
+pub use ellipse::Ellipse;
+pub use regular_polygon::RegularPolygon;
+pub use polygon::{Polygon, Winding};
+pub use cylinder::Cylinder;
+pub use cone::Cone;
+pub use square_pyramid::SquarePyramid;
+pub use torus::Torus;
+pub use ellipsoid::Ellipsoid;
+pub use capsule::Capsule;
+pub use frustum::Frustum;
+pub use spherical_cap::SphericalCap;
+pub use spherical_segment::SphericalSegment;
+pub use spherical_shell::SphericalShell;
+pub use hollow::{Hollow, Inset};
+pub use mesh::Mesh;
+pub use bounded::Bounded;
+pub use outline::{Outline, Outlined, Projected, View};
+pub use tessellate::{Resolution, Tessellate};
+pub use error::ShapeError;
 
-pub trait Shape<T> {
-    fn area(&self) -> T;
-    fn volume(&self) -> T;
-}
\ No newline at end of file
+use crate::utils::{Policy, Scalar};
+
+/// Behaviour shared by every shape, planar or solid
+pub trait Shape<T>
+where T:
+    Scalar
+{
+    /// Area under the given overflow and rounding policy, `None` if a checked calculation overflowed
+    fn area_with(&self, policy: Policy) -> Option<T>;
+
+    /// Area of a planar shape, or the surface area of a solid
+    ///
+    /// Integer and other exact scalars panic if the calculation overflows `T`; use
+    /// `checked_area` to handle that case. Floats follow IEEE 754, so an area too large
+    /// for the type is infinite and a NaN dimension gives a NaN area.
+    fn area(&self) -> T {
+        self.area_with(T::default_policy()).expect("area overflowed")
+    }
+
+    fn checked_area(&self) -> Option<T> {
+        self.area_with(Policy::default())
+    }
+
+    /// The area in `f64` before it is rounded into `T`
+    ///
+    /// Shapes that measure through `f64` return the unrounded value, so wrappers that scale
+    /// it, like `Placed`, only round once. The default converts `area` and gives NaN when
+    /// that overflows, rather than a clamped or wrapped value.
+    fn area_f64(&self) -> f64 {
+        self.area_with(T::default_policy()).map_or(f64::NAN, |area| area.to_f64())
+    }
+}
+
+/// Describes a flat shape with an area and a perimeter
+pub trait Shape2D<T>: Shape<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T;
+
+    /// The perimeter in `f64` before it is rounded into `T`, like `Shape::area_f64`
+    fn perimeter_f64(&self) -> f64 {
+        self.perimeter().to_f64()
+    }
+}
+
+/// Describes a solid shape with a surface area and a volume
+pub trait Shape3D<T>: Shape<T>
+where T:
+    Scalar
+{
+    /// Volume under the given overflow and rounding policy, `None` if a checked calculation overflowed
+    fn volume_with(&self, policy: Policy) -> Option<T>;
+
+    /// Integer and other exact scalars panic if the calculation overflows `T`; use
+    /// `checked_volume` to handle that case. Floats follow IEEE 754 like `area`.
+    fn volume(&self) -> T {
+        self.volume_with(T::default_policy()).expect("volume overflowed")
+    }
+
+    fn checked_volume(&self) -> Option<T> {
+        self.volume_with(Policy::default())
+    }
+
+    /// The volume in `f64` before it is rounded into `T`, like `Shape::area_f64`
+    fn volume_f64(&self) -> f64 {
+        self.volume_with(T::default_policy()).map_or(f64::NAN, |volume| volume.to_f64())
+    }
+
+    fn surface_area(&self) -> T {
+        self.area()
+    }
+}
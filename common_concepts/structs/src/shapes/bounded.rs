@@ -0,0 +1,61 @@
+use super::Outline;
+use crate::{
+    geometry::{Aabb, Point3, Quaternion, Vector3},
+    utils::{Rounding, Scalar},
+};
+
+/// A shape whose extent in space is known, for quick overlap checks and layout
+pub trait Bounded<T>
+where T:
+    Scalar
+{
+    /// The box around the shape in its own coordinates
+    ///
+    /// Everything except a Polygon or Mesh is centred on the origin, matching `Tessellate` and `Outlined`;
+    /// triangles are centred on their centroid, so their box can sit off centre.
+    /// This differs from `bounding_box`, which moves the box so its smallest corner is on the origin.
+    fn local_bounds(&self) -> Aabb<f64>;
+
+    /// The box around the shape with its smallest corner on the origin, since a shape has no position
+    ///
+    /// Only the size is kept from `local_bounds`, so a centred shape's box runs from the origin
+    /// to its size rather than being centred too. Polygons and meshes keep the coordinates of
+    /// their vertices. Sizes that are not whole values of `T` are rounded up so the box still
+    /// holds the shape. Use `Placed` for a box at the shape's position.
+    fn bounding_box(&self) -> Aabb<T> {
+        let [x, y, z] = self.local_bounds().size().map(|size| T::from_f64_rounded(size, Rounding::Ceil));
+        Aabb::with_size(x, y, z)
+    }
+
+    /// The box around the shape in its own coordinates after turning it about the origin
+    ///
+    /// By default this holds the turned corners of `local_bounds`, which can be larger than
+    /// needed; round shapes give the exact box.
+    fn rotated_bounds(&self, rotation: Quaternion) -> Aabb<f64> {
+        let corners = self.local_bounds().corners().map(|corner| {
+            let turned = rotation.rotate(Vector3::from(corner));
+            Point3::new(turned.x, turned.y, turned.z)
+        });
+        Aabb::around(corners).expect("a box has corners")
+    }
+}
+
+/// A box of the given size centred on the origin
+pub(crate) fn centred(x: f64, y: f64, z: f64) -> Aabb<f64> {
+    Aabb::new(Point3::new(-x / 2.0, -y / 2.0, -z / 2.0), Point3::new(x / 2.0, y / 2.0, z / 2.0))
+}
+
+/// The box around a planar outline, with no thickness
+pub(crate) fn outline_bounds(outline: &Outline) -> Aabb<f64> {
+    let (min, max) = outline.bounds();
+    Aabb::new(Point3::new(min.x, min.y, 0.0), Point3::new(max.x, max.y, 0.0))
+}
+
+/// The exact box around a turned cylinder of `height` along z, or a flat disc when `height` is zero
+///
+/// Each axis gets the shadow of the central line plus the widest reach of the round ends.
+pub(crate) fn rotated_cylinder(radius: f64, height: f64, rotation: Quaternion) -> Aabb<f64> {
+    let axis = rotation.rotate(Vector3::Z);
+    let reach = [axis.x, axis.y, axis.z].map(|a| height / 2.0 * a.abs() + radius * (1.0 - a * a).max(0.0).sqrt());
+    Aabb::new(Point3::new(-reach[0], -reach[1], -reach[2]), Point3::new(reach[0], reach[1], reach[2]))
+}
@@ -0,0 +1,126 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::{Aabb, Point3, Quaternion, Vector3},
+    utils::{Ops, PiMultiple, Policy, Scalar},
+};
+
+/// Describes a Capsule, a cylinder capped with a half sphere at each end
+pub struct Capsule<T>
+where T:
+    Scalar
+{
+    radius: T,
+    length: T,
+}
+
+impl<T> Capsule<T>
+where T:
+    Scalar
+{
+    /// `length` is the straight section between the two caps
+    pub fn new(radius: T, length: T) -> Self {
+        Self {
+            radius,
+            length,
+        }
+    }
+
+    pub fn new_with_diameter(diameter: T, length: T) -> Self {
+        Self::new(Ops::half(diameter), length)
+    }
+
+    /// Builds a Capsule, rejecting a bad radius or a negative length
+    ///
+    /// A length of zero is allowed, which makes the capsule a sphere.
+    pub fn try_new(radius: T, length: T) -> Result<Self, ShapeError> {
+        if length == T::zero() {
+            return validate("radius", radius).map(|radius| Self::new(radius, length));
+        }
+        Ok(Self::new(validate("radius", radius)?, validate("length", length)?))
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn diameter(&self) -> T {
+        Ops::double(self.radius)
+    }
+
+    pub fn length(&self) -> T {
+        self.length
+    }
+
+    /// Length from the tip of one cap to the tip of the other
+    pub fn total_length(&self) -> T {
+        self.length + Ops::double(self.radius)
+    }
+
+    /// The surface area as an exact multiple of pi
+    pub fn exact_area(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::double(self.radius) * (Ops::double(self.radius) + self.length), T::one(), 1)
+    }
+
+    /// The volume as an exact multiple of pi
+    pub fn exact_volume(&self) -> PiMultiple<T> {
+        let four_r = T::from_f64(4.0) * self.radius;
+        let three_a = T::from_f64(3.0) * self.length;
+        PiMultiple::new(Ops::square(self.radius) * (four_r + three_a), T::from_f64(3.0), 1)
+    }
+}
+
+/// Allow a Capsule to return its surface area
+impl<T> Shape<T> for Capsule<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.radius.to_f64();
+        2.0 * PI * radius * (2.0 * radius + self.length.to_f64())
+    }
+}
+
+/// Allow a Capsule to return its volume
+impl<T> Shape3D<T> for Capsule<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let radius = self.radius.to_f64();
+        PI * radius * radius * (4.0 / 3.0 * radius + self.length.to_f64())
+    }
+}
+
+/// Allow a Capsule standing on the z axis to report the box around it
+impl<T> Bounded<T> for Capsule<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let diameter = 2.0 * self.radius.to_f64();
+        centred(diameter, diameter, self.total_length().to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        let diameter = self.diameter();
+        Aabb::with_size(diameter, diameter, self.total_length())
+    }
+
+    fn rotated_bounds(&self, rotation: Quaternion) -> Aabb<f64> {
+        // The central line's shadow plus the radius of the rounded ends in every direction
+        let axis = rotation.rotate(Vector3::Z);
+        let (half, radius) = (self.length.to_f64() / 2.0, self.radius.to_f64());
+        let reach = [axis.x, axis.y, axis.z].map(|a| half * a.abs() + radius);
+        Aabb::new(Point3::new(-reach[0], -reach[1], -reach[2]), Point3::new(reach[0], reach[1], reach[2]))
+    }
+}
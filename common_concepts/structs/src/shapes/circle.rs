@@ -1,20 +1,17 @@
-// Import our Shape trait
-use super::Shape;
-// Import out utility math traits
-use crate::utils::{FromFloat, ToFloat, Ops};
-// Import the needed traits from the standard library
-use std::{
-    cmp::{PartialEq, PartialOrd}, 
-    fmt::{Debug, Display}, 
-    ops::{Add, Div, Mul, Sub},
+// Import our Shape traits
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::{centred, rotated_cylinder}, error::validate};
+// Import the value of pi from the standard library
+use std::f64::consts::PI;
+// Import out utility math types
+use crate::{
+    geometry::{Aabb, Quaternion},
+    utils::{Ops, PiMultiple, Policy, Scalar},
 };
 
 /// Describes a Circle
 pub struct Circle<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     radius: T,
     diameter: T,
@@ -22,9 +19,7 @@ where T:
 
 impl<T> Circle<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     pub fn new_with_radius(radius: T) -> Self {
         Self {
@@ -40,6 +35,18 @@ where T:
         }
     }
 
+    /// Builds a Circle, rejecting a radius that is not a positive finite number
+    pub fn try_new_with_radius(radius: T) -> Result<Self, ShapeError> {
+        validate("radius", radius).map(Self::new_with_radius)
+    }
+
+    /// Builds a Circle, rejecting a diameter that is not a positive finite number
+    pub fn try_new_with_diameter(diameter: T) -> Result<Self, ShapeError> {
+        let circle = Self::new_with_diameter(validate("diameter", diameter)?);
+        validate("radius", circle.radius)?;
+        Ok(circle)
+    }
+
     pub fn radius(&self) -> T {
         self.radius
     }
@@ -47,21 +54,76 @@ where T:
     pub fn diameter(&self) -> T {
         self.diameter
     }
+
+    /// Half the diameter in `f64`, so an odd integer diameter is not truncated like `radius`
+    pub fn radius_f64(&self) -> f64 {
+        self.diameter.to_f64() / 2.0
+    }
+
+    /// The area as an exact multiple of pi, built from the diameter so an odd integer diameter stays exact
+    pub fn exact_area(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::square(self.diameter), T::from_f64(4.0), 1)
+    }
+
+    /// The perimeter as an exact multiple of pi
+    pub fn exact_perimeter(&self) -> PiMultiple<T> {
+        PiMultiple::new(self.diameter, T::one(), 1)
+    }
 }
 
-/// Allow a Circle to return its area and volume properties
+/// Allow a Circle to return its area
 impl<T> Shape<T> for Circle<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
-    fn area(&self) -> T {
-        let pi = T::from_f64(std::f64::consts::PI);
-        pi * Ops::square(self.radius)
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.radius_f64();
+        PI * radius * radius
     }
+}
 
-    fn volume(&self) -> T {
-        T::from_f64(0.0)
+/// Allow a Circle to return its perimeter (circumference)
+impl<T> Shape2D<T> for Circle<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        T::from_f64(self.perimeter_f64())
     }
-}
\ No newline at end of file
+
+    fn perimeter_f64(&self) -> f64 {
+        2.0 * PI * self.radius_f64()
+    }
+}
+/// Allow a Circle to be drawn around the origin
+impl<T> Outlined<T> for Circle<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        Outline::Circle { radius: self.radius_f64() }
+    }
+}
+
+/// Allow a Circle to report the box around it, lying flat in the xy plane
+impl<T> Bounded<T> for Circle<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let diameter = self.diameter.to_f64();
+        centred(diameter, diameter, 0.0)
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.diameter, self.diameter, T::zero())
+    }
+
+    fn rotated_bounds(&self, rotation: Quaternion) -> Aabb<f64> {
+        rotated_cylinder(self.radius_f64(), 0.0, rotation)
+    }
+}
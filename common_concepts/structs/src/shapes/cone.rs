@@ -0,0 +1,119 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, PiMultiple, Policy, Scalar},
+};
+
+/// Describes a right circular Cone
+pub struct Cone<T>
+where T:
+    Scalar
+{
+    radius: T,
+    height: T,
+}
+
+impl<T> Cone<T>
+where T:
+    Scalar
+{
+    pub fn new(radius: T, height: T) -> Self {
+        Self {
+            radius,
+            height,
+        }
+    }
+
+    pub fn new_with_diameter(diameter: T, height: T) -> Self {
+        Self::new(Ops::half(diameter), height)
+    }
+
+    /// Builds a Cone, rejecting any dimension that is not a positive finite number
+    pub fn try_new(radius: T, height: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(validate("radius", radius)?, validate("height", height)?))
+    }
+
+    /// Builds a Cone, rejecting any dimension that is not a positive finite number
+    pub fn try_new_with_diameter(diameter: T, height: T) -> Result<Self, ShapeError> {
+        let cone = Self::new_with_diameter(validate("diameter", diameter)?, validate("height", height)?);
+        validate("radius", cone.radius)?;
+        Ok(cone)
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn diameter(&self) -> T {
+        Ops::double(self.radius)
+    }
+
+    pub fn height(&self) -> T {
+        self.height
+    }
+
+    /// Distance from the tip to the edge of the base
+    pub fn slant_height(&self) -> T {
+        Ops::hypot(self.radius, self.height)
+    }
+
+    /// The area of the sloped side, without the base
+    pub fn lateral_area(&self) -> T {
+        let radius = self.radius.to_f64();
+        T::from_f64(PI * radius * radius.hypot(self.height.to_f64()))
+    }
+
+    /// The volume as an exact multiple of pi
+    pub fn exact_volume(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::square(self.radius) * self.height, T::from_f64(3.0), 1)
+    }
+}
+
+/// Allow a Cone to return its surface area
+impl<T> Shape<T> for Cone<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.radius.to_f64();
+        let slant = radius.hypot(self.height.to_f64());
+        PI * radius * (radius + slant)
+    }
+}
+
+/// Allow a Cone to return its volume
+impl<T> Shape3D<T> for Cone<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let radius = self.radius.to_f64();
+        PI * radius * radius * self.height.to_f64() / 3.0
+    }
+}
+
+/// Allow a Cone standing on the z axis to report the box around it
+impl<T> Bounded<T> for Cone<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let diameter = 2.0 * self.radius.to_f64();
+        centred(diameter, diameter, self.height.to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        let diameter = self.diameter();
+        Aabb::with_size(diameter, diameter, self.height)
+    }
+}
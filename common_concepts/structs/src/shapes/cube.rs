@@ -1,26 +1,20 @@
-use std::{
-    cmp::{PartialEq, PartialOrd}, 
-    fmt::{Debug, Display}, 
-    ops::{Add, Div, Mul, Sub},
+use super::{Bounded, Inset, Mesh, Outline, Projected, Resolution, Shape, Shape3D, ShapeError, Tessellate, View, bounded::centred, error::validate, hollow::check_wall, outline::rectangle_outline, tessellate::box_mesh};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
 };
-use super::Shape;
-use crate::utils::{FromFloat, Ops, ToFloat};
 
 /// Describes a Cube
 pub struct Cube<T> 
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     sides: T,
 }
 
 impl<T> Cube<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     pub fn new(sides: T) -> Self {
         Self {
@@ -28,23 +22,79 @@ where T:
         }
     }
 
+    /// Builds a Cube, rejecting sides that are not a positive finite number
+    pub fn try_new(sides: T) -> Result<Self, ShapeError> {
+        validate("sides", sides).map(Self::new)
+    }
+
     pub fn sides(&self) -> T {
         self.sides
     }
 }
 
-/// Allow a Cube to return its area and volume properties
+/// Allow a Cube to return its surface area
 impl<T> Shape<T> for Cube<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T>
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        let overflow = policy.overflow;
+        Ops::mul_with(T::from_f64(6.0), Ops::square_with(self.sides, overflow)?, overflow)
+    }
+}
+
+/// Allow a Cube to return its volume
+impl<T> Shape3D<T> for Cube<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::cube_with(self.sides, policy.overflow)
+    }
+}
+
+/// Allow a Cube to be hollowed out
+impl<T> Inset<T> for Cube<T>
+where T:
+    Scalar
+{
+    fn inset(&self, thickness: T) -> Result<Self, ShapeError> {
+        let thickness = check_wall(thickness, self.sides)?;
+        Ok(Self::new(self.sides - Ops::double(thickness)))
+    }
+}
+
+/// Allow a Cube to be turned into triangles
+impl<T> Tessellate<T> for Cube<T>
+where T:
+    Scalar
+{
+    fn tessellate(&self, _resolution: Resolution) -> Mesh<f64> {
+        box_mesh(self.sides.to_f64(), self.sides.to_f64(), self.sides.to_f64())
+    }
+}
+
+/// Allow a Cube to be drawn from any side as a square
+impl<T> Projected<T> for Cube<T>
+where T:
+    Scalar
 {
-    fn area(&self) -> T {
-        T::from_f64(6.0) * Ops::square(self.sides)
+    fn silhouette(&self, _view: View) -> Outline {
+        rectangle_outline(self.sides.to_f64(), self.sides.to_f64())
     }
+}
 
-    fn volume(&self) -> T {
-        Ops::cube(self.sides)
+/// Allow a Cube to report the box around it
+impl<T> Bounded<T> for Cube<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let side = self.sides.to_f64();
+        centred(side, side, side)
     }
-}
\ No newline at end of file
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.sides, self.sides, self.sides)
+    }
+}
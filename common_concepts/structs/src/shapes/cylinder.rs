@@ -0,0 +1,156 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Inset, Mesh, Outline, Projected, Resolution, Shape, Shape3D, ShapeError, Tessellate, View, bounded::{centred, rotated_cylinder}, error::validate, hollow::check_wall, outline::rectangle_outline, tessellate::cylinder_mesh};
+use crate::{
+    geometry::{Aabb, Quaternion},
+    utils::{Ops, PiMultiple, Policy, Scalar},
+};
+
+/// Describes a Cylinder
+pub struct Cylinder<T>
+where T:
+    Scalar
+{
+    radius: T,
+    height: T,
+}
+
+impl<T> Cylinder<T>
+where T:
+    Scalar
+{
+    pub fn new(radius: T, height: T) -> Self {
+        Self {
+            radius,
+            height,
+        }
+    }
+
+    pub fn new_with_diameter(diameter: T, height: T) -> Self {
+        Self::new(Ops::half(diameter), height)
+    }
+
+    /// Builds a Cylinder, rejecting any dimension that is not a positive finite number
+    pub fn try_new(radius: T, height: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(validate("radius", radius)?, validate("height", height)?))
+    }
+
+    /// Builds a Cylinder, rejecting any dimension that is not a positive finite number
+    pub fn try_new_with_diameter(diameter: T, height: T) -> Result<Self, ShapeError> {
+        let cylinder = Self::new_with_diameter(validate("diameter", diameter)?, validate("height", height)?);
+        validate("radius", cylinder.radius)?;
+        Ok(cylinder)
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn diameter(&self) -> T {
+        Ops::double(self.radius)
+    }
+
+    pub fn height(&self) -> T {
+        self.height
+    }
+
+    /// The area of the curved side, without the two ends
+    pub fn lateral_area(&self) -> T {
+        T::from_f64(2.0 * PI * self.radius.to_f64() * self.height.to_f64())
+    }
+
+    /// The surface area as an exact multiple of pi
+    pub fn exact_area(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::double(self.radius) * (self.radius + self.height), T::one(), 1)
+    }
+
+    /// The volume as an exact multiple of pi
+    pub fn exact_volume(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::square(self.radius) * self.height, T::one(), 1)
+    }
+}
+
+/// Allow a Cylinder to return its surface area
+impl<T> Shape<T> for Cylinder<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.radius.to_f64();
+        2.0 * PI * radius * (radius + self.height.to_f64())
+    }
+}
+
+/// Allow a Cylinder to return its volume
+impl<T> Shape3D<T> for Cylinder<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let radius = self.radius.to_f64();
+        PI * radius * radius * self.height.to_f64()
+    }
+}
+
+/// Allow a Cylinder to be hollowed out as a closed tank, with walls on the side and both ends
+impl<T> Inset<T> for Cylinder<T>
+where T:
+    Scalar
+{
+    fn inset(&self, thickness: T) -> Result<Self, ShapeError> {
+        let thickness = check_wall(thickness, Ops::min(self.diameter(), self.height))?;
+        Ok(Self::new(self.radius - thickness, self.height - Ops::double(thickness)))
+    }
+}
+
+/// Allow a Cylinder to be turned into triangles, with as many slices around it as the resolution has segments
+impl<T> Tessellate<T> for Cylinder<T>
+where T:
+    Scalar
+{
+    fn tessellate(&self, resolution: Resolution) -> Mesh<f64> {
+        cylinder_mesh(self.radius.to_f64(), self.height.to_f64(), resolution.segments())
+    }
+}
+
+/// Allow a Cylinder standing on the z axis to be drawn
+impl<T> Projected<T> for Cylinder<T>
+where T:
+    Scalar
+{
+    fn silhouette(&self, view: View) -> Outline {
+        let radius = self.radius.to_f64();
+        match view {
+            View::Front | View::Side => rectangle_outline(2.0 * radius, self.height.to_f64()),
+            View::Top => Outline::Circle { radius },
+        }
+    }
+}
+
+/// Allow a Cylinder standing on the z axis to report the box around it
+impl<T> Bounded<T> for Cylinder<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let diameter = 2.0 * self.radius.to_f64();
+        centred(diameter, diameter, self.height.to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        let diameter = self.diameter();
+        Aabb::with_size(diameter, diameter, self.height)
+    }
+
+    fn rotated_bounds(&self, rotation: Quaternion) -> Aabb<f64> {
+        rotated_cylinder(self.radius.to_f64(), self.height.to_f64(), rotation)
+    }
+}
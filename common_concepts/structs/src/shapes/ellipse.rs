@@ -0,0 +1,112 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, PiMultiple, Policy, Scalar},
+};
+
+/// Describes an Ellipse by its two semi-axes
+pub struct Ellipse<T>
+where T:
+    Scalar
+{
+    semi_major: T,
+    semi_minor: T,
+}
+
+impl<T> Ellipse<T>
+where T:
+    Scalar
+{
+    /// Builds an Ellipse, swapping the axes if the minor one is given first
+    pub fn new(semi_major: T, semi_minor: T) -> Self {
+        Self {
+            semi_major: Ops::max(semi_major, semi_minor),
+            semi_minor: Ops::min(semi_major, semi_minor),
+        }
+    }
+
+    pub fn new_with_diameters(major: T, minor: T) -> Self {
+        Self::new(Ops::half(major), Ops::half(minor))
+    }
+
+    /// Builds an Ellipse, rejecting semi-axes that are not positive finite numbers
+    pub fn try_new(semi_major: T, semi_minor: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(validate("semi_major", semi_major)?, validate("semi_minor", semi_minor)?))
+    }
+
+    pub fn semi_major(&self) -> T {
+        self.semi_major
+    }
+
+    pub fn semi_minor(&self) -> T {
+        self.semi_minor
+    }
+
+    /// How far the ellipse is from a circle, from `0` (a circle) towards `1`
+    pub fn eccentricity(&self) -> f64 {
+        let ratio = self.semi_minor.to_f64() / self.semi_major.to_f64();
+        (1.0 - ratio * ratio).sqrt()
+    }
+
+    /// The area as an exact multiple of pi
+    pub fn exact_area(&self) -> PiMultiple<T> {
+        PiMultiple::new(self.semi_major * self.semi_minor, T::one(), 1)
+    }
+}
+
+/// Allow an Ellipse to return its area
+impl<T> Shape<T> for Ellipse<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        PI * self.semi_major.to_f64() * self.semi_minor.to_f64()
+    }
+}
+
+/// Allow an Ellipse to return its perimeter, using Ramanujan's second approximation
+impl<T> Shape2D<T> for Ellipse<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        T::from_f64(self.perimeter_f64())
+    }
+
+    fn perimeter_f64(&self) -> f64 {
+        let a = self.semi_major.to_f64();
+        let b = self.semi_minor.to_f64();
+        let h = ((a - b) / (a + b)).powi(2);
+        PI * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+    }
+}
+
+/// Allow an Ellipse to be drawn around the origin, lying along its major axis
+impl<T> Outlined<T> for Ellipse<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        Outline::Ellipse { semi_major: self.semi_major.to_f64(), semi_minor: self.semi_minor.to_f64() }
+    }
+}
+
+/// Allow an Ellipse to report the box around it, lying flat with its major axis along x
+impl<T> Bounded<T> for Ellipse<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        centred(2.0 * self.semi_major.to_f64(), 2.0 * self.semi_minor.to_f64(), 0.0)
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(Ops::double(self.semi_major), Ops::double(self.semi_minor), T::zero())
+    }
+}
@@ -0,0 +1,98 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, PiMultiple, Policy, Scalar},
+};
+
+/// Exponent of the Knud Thomsen surface area approximation, accurate to about 1%
+const THOMSEN_P: f64 = 1.6075;
+
+/// Describes an Ellipsoid by its three semi-axes
+pub struct Ellipsoid<T>
+where T:
+    Scalar
+{
+    a: T,
+    b: T,
+    c: T,
+}
+
+impl<T> Ellipsoid<T>
+where T:
+    Scalar
+{
+    pub fn new(a: T, b: T, c: T) -> Self {
+        Self {
+            a,
+            b,
+            c,
+        }
+    }
+
+    pub fn new_with_diameters(a: T, b: T, c: T) -> Self {
+        Self::new(Ops::half(a), Ops::half(b), Ops::half(c))
+    }
+
+    /// Builds an Ellipsoid, rejecting semi-axes that are not positive finite numbers
+    pub fn try_new(a: T, b: T, c: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(validate("a", a)?, validate("b", b)?, validate("c", c)?))
+    }
+
+    pub fn semi_axes(&self) -> [T; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// The volume as an exact multiple of pi
+    pub fn exact_volume(&self) -> PiMultiple<T> {
+        PiMultiple::new(T::from_f64(4.0) * self.a * self.b * self.c, T::from_f64(3.0), 1)
+    }
+}
+
+/// Allow an Ellipsoid to return its surface area, using the Knud Thomsen approximation
+impl<T> Shape<T> for Ellipsoid<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let [a, b, c] = self.semi_axes().map(|axis| axis.to_f64().powf(THOMSEN_P));
+        let mean = (a * b + a * c + b * c) / 3.0;
+        4.0 * PI * mean.powf(1.0 / THOMSEN_P)
+    }
+}
+
+/// Allow an Ellipsoid to return its volume
+impl<T> Shape3D<T> for Ellipsoid<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let [a, b, c] = self.semi_axes().map(|axis| axis.to_f64());
+        4.0 / 3.0 * PI * a * b * c
+    }
+}
+
+/// Allow an Ellipsoid to report the box around it, with its semi-axes along x, y and z
+impl<T> Bounded<T> for Ellipsoid<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let [a, b, c] = self.semi_axes().map(|axis| 2.0 * axis.to_f64());
+        centred(a, b, c)
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        let [a, b, c] = self.semi_axes().map(Ops::double);
+        Aabb::with_size(a, b, c)
+    }
+}
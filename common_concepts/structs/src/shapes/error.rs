@@ -0,0 +1,70 @@
+use std::{error::Error, fmt};
+
+use crate::utils::Scalar;
+
+/// Describes why a shape could not be built from the given dimensions
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShapeError {
+    /// A dimension was less than zero
+    Negative { dimension: &'static str, value: f64 },
+    /// A dimension was NaN or infinite
+    NonFinite { dimension: &'static str, value: f64 },
+    /// A dimension was zero, so the shape would collapse
+    Degenerate { dimension: &'static str },
+    /// The dimensions are valid alone but cannot describe the shape together
+    Impossible { reason: &'static str },
+    /// A wall is so thick that it would fill the solid
+    WallTooThick { thickness: f64, limit: f64 },
+    /// Two edges of a polygon, given by index, cross or overlap
+    SelfIntersecting { first_edge: usize, second_edge: usize },
+    /// The mesh edge between two vertex indices belongs to only one triangle, leaving a hole
+    OpenMesh { from: usize, to: usize },
+    /// Two triangles run along the mesh edge between two vertex indices in the same direction
+    InconsistentWinding { from: usize, to: usize },
+    /// The mesh edge between two vertex indices is shared by more than two triangles
+    NonManifold { from: usize, to: usize },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Negative { dimension, value } => write!(f, "{dimension} must not be negative, got {value}"),
+            Self::NonFinite { dimension, value } => write!(f, "{dimension} must be a finite number, got {value}"),
+            Self::Degenerate { dimension } => write!(f, "{dimension} must be greater than zero"),
+            Self::Impossible { reason } => write!(f, "{reason}"),
+            Self::WallTooThick { thickness, limit } => {
+                write!(f, "wall thickness must be less than {limit}, got {thickness}")
+            }
+            Self::SelfIntersecting { first_edge, second_edge } => {
+                write!(f, "edges {first_edge} and {second_edge} of the polygon intersect")
+            }
+            Self::OpenMesh { from, to } => write!(f, "mesh is open along the edge from vertex {from} to {to}"),
+            Self::InconsistentWinding { from, to } => {
+                write!(f, "triangles disagree on orientation along the edge from vertex {from} to {to}")
+            }
+            Self::NonManifold { from, to } => {
+                write!(f, "more than two triangles share the edge from vertex {from} to {to}")
+            }
+        }
+    }
+}
+
+impl Error for ShapeError {}
+
+/// Checks that a dimension is finite and greater than zero
+pub(crate) fn validate<T>(dimension: &'static str, value: T) -> Result<T, ShapeError>
+where T:
+    Scalar
+{
+    let float = value.to_f64();
+
+    if !float.is_finite() {
+        Err(ShapeError::NonFinite { dimension, value: float })
+    } else if value < T::zero() {
+        Err(ShapeError::Negative { dimension, value: float })
+    } else if value == T::zero() {
+        Err(ShapeError::Degenerate { dimension })
+    } else {
+        Ok(value)
+    }
+}
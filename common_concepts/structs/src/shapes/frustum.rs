@@ -0,0 +1,124 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a Frustum, a cone with its tip cut off parallel to the base
+pub struct Frustum<T>
+where T:
+    Scalar
+{
+    bottom_radius: T,
+    top_radius: T,
+    height: T,
+}
+
+impl<T> Frustum<T>
+where T:
+    Scalar
+{
+    pub fn new(bottom_radius: T, top_radius: T, height: T) -> Self {
+        Self {
+            bottom_radius,
+            top_radius,
+            height,
+        }
+    }
+
+    pub fn new_with_diameters(bottom_diameter: T, top_diameter: T, height: T) -> Self {
+        Self::new(Ops::half(bottom_diameter), Ops::half(top_diameter), height)
+    }
+
+    /// Builds a Frustum, rejecting bad dimensions
+    ///
+    /// A top radius of zero is allowed, which makes the frustum a cone.
+    pub fn try_new(bottom_radius: T, top_radius: T, height: T) -> Result<Self, ShapeError> {
+        let bottom_radius = validate("bottom_radius", bottom_radius)?;
+        let height = validate("height", height)?;
+        if top_radius != T::zero() {
+            validate("top_radius", top_radius)?;
+        }
+        Ok(Self::new(bottom_radius, top_radius, height))
+    }
+
+    pub fn bottom_radius(&self) -> T {
+        self.bottom_radius
+    }
+
+    pub fn top_radius(&self) -> T {
+        self.top_radius
+    }
+
+    pub fn height(&self) -> T {
+        self.height
+    }
+
+    /// Distance along the sloped side from the bottom edge to the top edge
+    pub fn slant_height(&self) -> T {
+        T::from_f64(self.slant_height_f64())
+    }
+
+    /// The area of the sloped side, without the two ends
+    pub fn curved_area(&self) -> T {
+        T::from_f64(self.curved_area_f64())
+    }
+
+    fn slant_height_f64(&self) -> f64 {
+        (self.bottom_radius.to_f64() - self.top_radius.to_f64()).hypot(self.height.to_f64())
+    }
+
+    fn curved_area_f64(&self) -> f64 {
+        PI * (self.bottom_radius.to_f64() + self.top_radius.to_f64()) * self.slant_height_f64()
+    }
+}
+
+/// Allow a Frustum to return its total surface area, including both ends
+impl<T> Shape<T> for Frustum<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let bottom = self.bottom_radius.to_f64();
+        let top = self.top_radius.to_f64();
+        self.curved_area_f64() + PI * (bottom * bottom + top * top)
+    }
+}
+
+/// Allow a Frustum to return its volume
+impl<T> Shape3D<T> for Frustum<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let bottom = self.bottom_radius.to_f64();
+        let top = self.top_radius.to_f64();
+        PI * self.height.to_f64() * (bottom * bottom + bottom * top + top * top) / 3.0
+    }
+}
+
+/// Allow a Frustum standing on the z axis to report the box around it
+impl<T> Bounded<T> for Frustum<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let diameter = 2.0 * self.bottom_radius.to_f64().max(self.top_radius.to_f64());
+        centred(diameter, diameter, self.height.to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        let diameter = Ops::double(Ops::max(self.bottom_radius, self.top_radius));
+        Aabb::with_size(diameter, diameter, self.height)
+    }
+}
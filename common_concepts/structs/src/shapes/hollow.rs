@@ -0,0 +1,178 @@
+use super::{Bounded, Shape, Shape3D, ShapeError, error::validate};
+use crate::{
+    geometry::{Aabb, Quaternion},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// A solid whose walls can be moved inwards to find the space they enclose
+pub trait Inset<T>: Shape3D<T> + Sized
+where T:
+    Scalar
+{
+    /// The same solid shrunk by `thickness` on every face
+    ///
+    /// Returns `ShapeError::WallTooThick` when the opposite walls would meet.
+    fn inset(&self, thickness: T) -> Result<Self, ShapeError>;
+}
+
+/// Checks that walls of `thickness` leave room inside an extent of `extent`
+pub(crate) fn check_wall<T>(thickness: T, extent: T) -> Result<T, ShapeError>
+where T:
+    Scalar
+{
+    let thickness = validate("thickness", thickness)?;
+    if Ops::double(thickness) >= extent {
+        return Err(ShapeError::WallTooThick { thickness: thickness.to_f64(), limit: Ops::half(extent.to_f64()) });
+    }
+    Ok(thickness)
+}
+
+/// A thick walled version of a solid, such as a tank or a box
+pub struct Hollow<S> {
+    outer: S,
+    inner: S,
+}
+
+impl<S> Hollow<S> {
+    /// Builds a Hollow solid from its outside shape and the thickness of its walls
+    pub fn new<T>(outer: S, thickness: T) -> Result<Self, ShapeError>
+    where S:
+        Inset<T>,
+    T:
+        Scalar
+    {
+        let inner = outer.inset(thickness)?;
+        Ok(Self {
+            outer,
+            inner,
+        })
+    }
+
+    pub fn outer(&self) -> &S {
+        &self.outer
+    }
+
+    /// The empty space inside the walls
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The volume taken up by the walls themselves, rounded once like `volume`
+    pub fn material_volume<T>(&self) -> T
+    where S:
+        Shape3D<T>,
+    T:
+        Scalar
+    {
+        self.volume()
+    }
+
+    /// How much the solid can hold
+    pub fn capacity<T>(&self) -> T
+    where S:
+        Shape3D<T>,
+    T:
+        Scalar
+    {
+        self.inner.volume()
+    }
+
+    pub fn outer_area<T>(&self) -> T
+    where S:
+        Shape<T>,
+    T:
+        Scalar
+    {
+        self.outer.area()
+    }
+
+    pub fn inner_area<T>(&self) -> T
+    where S:
+        Shape<T>,
+    T:
+        Scalar
+    {
+        self.inner.area()
+    }
+}
+
+/// Combines the outside and inside measurements, each given in `T` and unrounded in `f64`
+///
+/// When neither was rounded, as for a Cube or a Rational solid, they are combined in `T` so the
+/// result stays exact. Otherwise the unrounded result is rounded once with the policy, rather
+/// than combining two values that were each rounded already.
+fn round_once<T>(
+    outer: (Option<T>, f64),
+    inner: (Option<T>, f64),
+    unrounded: f64,
+    combine: impl FnOnce(T, T) -> Option<T>,
+    policy: Policy,
+) -> Option<T>
+where T:
+    Scalar
+{
+    match (outer, inner) {
+        ((Some(a), a_f64), (Some(b), b_f64)) if unrounded.is_nan() || (a.to_f64() == a_f64 && b.to_f64() == b_f64) => {
+            combine(a, b)
+        }
+        // A part overflowed and has no unrounded measurement to fall back on
+        _ if unrounded.is_nan() => None,
+        _ => Ops::from_f64_with(unrounded, policy),
+    }
+}
+
+/// Allow a Hollow solid to return its surface area, inside and out
+impl<T, S> Shape<T> for Hollow<S>
+where T:
+    Scalar,
+S:
+    Shape<T>
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        let outer = (self.outer.area_with(policy), self.outer.area_f64());
+        let inner = (self.inner.area_with(policy), self.inner.area_f64());
+        round_once(outer, inner, outer.1 + inner.1, |a, b| Ops::add_with(a, b, policy.overflow), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        self.outer.area_f64() + self.inner.area_f64()
+    }
+}
+
+/// Allow a Hollow solid to return the volume of its walls
+impl<T, S> Shape3D<T> for Hollow<S>
+where T:
+    Scalar,
+S:
+    Shape3D<T>
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        let outer = (self.outer.volume_with(policy), self.outer.volume_f64());
+        let inner = (self.inner.volume_with(policy), self.inner.volume_f64());
+        round_once(outer, inner, outer.1 - inner.1, |a, b| Ops::sub_with(a, b, policy.overflow), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        self.outer.volume_f64() - self.inner.volume_f64()
+    }
+}
+
+/// Allow a Hollow solid to report the box around its outside
+impl<T, S> Bounded<T> for Hollow<S>
+where T:
+    Scalar,
+S:
+    Bounded<T>
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        self.outer.local_bounds()
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        self.outer.bounding_box()
+    }
+
+    fn rotated_bounds(&self, rotation: Quaternion) -> Aabb<f64> {
+        self.outer.rotated_bounds(rotation)
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use super::{Bounded, Shape, Shape3D, ShapeError};
+use crate::{
+    geometry::{Aabb, Point3},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes any solid by the triangles covering its surface, such as a part from a CAD model
+///
+/// Each triangle lists three indices into `vertices`, ordered counter-clockwise when seen
+/// from outside the solid.
+pub struct Mesh<T>
+where T:
+    Scalar
+{
+    vertices: Vec<Point3<T>>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl<T> Mesh<T>
+where T:
+    Scalar
+{
+    /// Builds a Mesh without checking that it encloses a solid
+    pub fn new(vertices: Vec<Point3<T>>, triangles: Vec<[usize; 3]>) -> Self {
+        Self {
+            vertices,
+            triangles,
+        }
+    }
+
+    /// Builds a Mesh, rejecting one that is not closed and consistently oriented
+    pub fn try_new(vertices: Vec<Point3<T>>, triangles: Vec<[usize; 3]>) -> Result<Self, ShapeError> {
+        let mesh = Self::new(vertices, triangles);
+        mesh.validate()?;
+        Ok(mesh)
+    }
+
+    pub fn vertices(&self) -> &[Point3<T>] {
+        &self.vertices
+    }
+
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Checks that every edge is shared by exactly two triangles that run along it in opposite directions
+    pub fn validate(&self) -> Result<(), ShapeError> {
+        if self.triangles.is_empty() {
+            return Err(ShapeError::Impossible { reason: "a mesh needs at least one triangle" });
+        }
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+
+        for &[a, b, c] in &self.triangles {
+            if [a, b, c].iter().any(|&index| index >= self.vertices.len()) {
+                return Err(ShapeError::Impossible { reason: "a triangle refers to a vertex that does not exist" });
+            }
+            if a == b || b == c || c == a {
+                return Err(ShapeError::Degenerate { dimension: "triangle" });
+            }
+            for edge in [(a, b), (b, c), (c, a)] {
+                *edges.entry(edge).or_default() += 1;
+            }
+        }
+
+        // Walk the triangles again so the reported edge does not depend on hash order
+        for &[a, b, c] in &self.triangles {
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                let forward = edges[&(from, to)];
+                let backward = edges.get(&(to, from)).copied().unwrap_or(0);
+                if forward + backward > 2 {
+                    return Err(ShapeError::NonManifold { from, to });
+                }
+                if forward > 1 {
+                    return Err(ShapeError::InconsistentWinding { from, to });
+                }
+                if backward == 0 {
+                    return Err(ShapeError::OpenMesh { from, to });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Volume enclosed by the surface, negative when the triangles face inwards
+    ///
+    /// Sums the signed tetrahedra formed by each triangle and the origin, which the
+    /// divergence theorem shows adds up to the enclosed volume for a closed surface.
+    pub fn signed_volume(&self) -> f64 {
+        let sum: f64 = self.corners().map(|[a, b, c]| dot(a, cross(b, c))).sum();
+        sum / 6.0
+    }
+
+    fn corners(&self) -> impl Iterator<Item = [[f64; 3]; 3]> + '_ {
+        self.triangles
+            .iter()
+            .map(|triangle| triangle.map(|index| self.vertices[index].to_f64()))
+    }
+}
+
+pub(crate) fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Area of the triangle with corners `a`, `b` and `c`
+pub(crate) fn triangle_area([a, b, c]: [[f64; 3]; 3]) -> f64 {
+    let normal = cross(sub(b, a), sub(c, a));
+    dot(normal, normal).sqrt() / 2.0
+}
+
+/// Allow a Mesh to return its surface area, the sum of its triangles
+impl<T> Shape<T> for Mesh<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        self.corners().map(triangle_area).sum()
+    }
+}
+
+/// Allow a Mesh to return the volume it encloses
+impl<T> Shape3D<T> for Mesh<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        self.signed_volume().abs()
+    }
+}
+
+/// Allow a Mesh to report the box around its vertices
+impl<T> Bounded<T> for Mesh<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let vertices = self.vertices.iter().map(|vertex| {
+            let [x, y, z] = vertex.to_f64();
+            Point3::new(x, y, z)
+        });
+        Aabb::around(vertices).unwrap_or_default()
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::around(self.vertices.iter().copied()).unwrap_or_else(|| Aabb::with_size(T::zero(), T::zero(), T::zero()))
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Shape2D, Shape3D};
+use crate::{geometry::Point2, utils::Scalar};
+
+/// The boundary of a planar shape in its own coordinates, with y pointing up
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outline {
+    /// A circle centred on the origin
+    Circle { radius: f64 },
+    /// An ellipse centred on the origin with its major axis along x
+    Ellipse { semi_major: f64, semi_minor: f64 },
+    /// Straight edges joining each vertex to the next and the last back to the first
+    Polygon { vertices: Vec<Point2<f64>> },
+}
+
+impl Outline {
+    /// The smallest and largest corners of the box around the outline
+    pub fn bounds(&self) -> (Point2<f64>, Point2<f64>) {
+        match self {
+            Self::Circle { radius } => (Point2::new(-radius, -radius), Point2::new(*radius, *radius)),
+            Self::Ellipse { semi_major, semi_minor } => {
+                (Point2::new(-semi_major, -semi_minor), Point2::new(*semi_major, *semi_minor))
+            }
+            Self::Polygon { vertices } => vertices.iter().fold(
+                (Point2::new(f64::INFINITY, f64::INFINITY), Point2::new(f64::NEG_INFINITY, f64::NEG_INFINITY)),
+                |(min, max), v| (Point2::new(min.x.min(v.x), min.y.min(v.y)), Point2::new(max.x.max(v.x), max.y.max(v.y))),
+            ),
+        }
+    }
+
+    /// Whether a point lies inside the outline or on its edge
+    pub fn contains(&self, point: Point2<f64>) -> bool {
+        match self {
+            Self::Circle { radius } => point.x * point.x + point.y * point.y <= radius * radius,
+            Self::Ellipse { semi_major, semi_minor } => {
+                (point.x / semi_major).powi(2) + (point.y / semi_minor).powi(2) <= 1.0
+            }
+            Self::Polygon { vertices } => {
+                // Count the edges crossed by a ray running right from the point
+                let mut inside = false;
+                for (index, a) in vertices.iter().enumerate() {
+                    let b = vertices[(index + 1) % vertices.len()];
+                    if (a.y > point.y) != (b.y > point.y) {
+                        let crossing = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                        if point.x < crossing {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            }
+        }
+    }
+}
+
+/// A direction to look at a solid from, without perspective
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum View {
+    /// Looking along y, so x runs across and z runs up
+    Front,
+    /// Looking along x, so y runs across and z runs up
+    Side,
+    /// Looking down z, so x runs across and y runs up
+    Top,
+}
+
+/// A planar shape that can be drawn, such as in an SVG diagram
+pub trait Outlined<T>: Shape2D<T>
+where T:
+    Scalar
+{
+    /// The boundary of the shape; everything except a Polygon is centred on the origin
+    fn outline(&self) -> Outline;
+}
+
+/// A solid whose orthographic projection can be drawn
+pub trait Projected<T>: Shape3D<T>
+where T:
+    Scalar
+{
+    /// The silhouette seen from `view`, centred on the origin
+    fn silhouette(&self, view: View) -> Outline;
+}
+
+/// Corners of an axis aligned rectangle centred on the origin, counter-clockwise from the bottom left
+pub(crate) fn rectangle_outline(length: f64, width: f64) -> Outline {
+    let (x, y) = (length / 2.0, width / 2.0);
+    Outline::Polygon { vertices: vec![Point2::new(-x, -y), Point2::new(x, -y), Point2::new(x, y), Point2::new(-x, y)] }
+}
@@ -0,0 +1,203 @@
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::outline_bounds};
+use crate::{
+    geometry::{Aabb, Point2, Point3},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// The direction a polygon's vertices travel around it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Describes any flat polygon by its corners in order, such as a surveyed plot of land
+pub struct Polygon<T>
+where T:
+    Scalar
+{
+    vertices: Vec<Point2<T>>,
+}
+
+impl<T> Polygon<T>
+where T:
+    Scalar
+{
+    /// Builds a Polygon without checking that its edges avoid each other
+    ///
+    /// Panics if there are fewer than three vertices.
+    pub fn new(vertices: Vec<Point2<T>>) -> Self {
+        assert!(vertices.len() >= 3, "a polygon needs at least 3 vertices, got {}", vertices.len());
+        Self {
+            vertices,
+        }
+    }
+
+    /// Builds a Polygon, rejecting bad coordinates, zero area or edges that cross
+    pub fn try_new(vertices: Vec<Point2<T>>) -> Result<Self, ShapeError> {
+        if vertices.len() < 3 {
+            return Err(ShapeError::Impossible { reason: "a polygon needs at least 3 vertices" });
+        }
+        for value in vertices.iter().flat_map(|p| [p.x, p.y]) {
+            let float = value.to_f64();
+            if !float.is_finite() {
+                return Err(ShapeError::NonFinite { dimension: "coordinate", value: float });
+            }
+        }
+        let polygon = Self::new(vertices);
+        if let Some((first_edge, second_edge)) = polygon.find_crossing() {
+            return Err(ShapeError::SelfIntersecting { first_edge, second_edge });
+        }
+        if polygon.signed_area() == 0.0 {
+            return Err(ShapeError::Degenerate { dimension: "area" });
+        }
+        Ok(polygon)
+    }
+
+    pub fn vertices(&self) -> &[Point2<T>] {
+        &self.vertices
+    }
+
+    pub fn winding(&self) -> Winding {
+        if self.signed_area() < 0.0 { Winding::Clockwise } else { Winding::CounterClockwise }
+    }
+
+    /// The balance point of the enclosed area, rounded for integer types
+    pub fn centroid(&self) -> Point2<T> {
+        let area = self.signed_area();
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for (a, b) in self.edges() {
+            let cross = a.0 * b.1 - b.0 * a.1;
+            cx += (a.0 + b.0) * cross;
+            cy += (a.1 + b.1) * cross;
+        }
+        Point2::new(T::from_f64(cx / (6.0 * area)), T::from_f64(cy / (6.0 * area)))
+    }
+
+    /// Whether no two edges touch other than neighbours meeting at their shared corner
+    pub fn is_simple(&self) -> bool {
+        self.find_crossing().is_none()
+    }
+
+    /// Shoelace area, positive when the vertices run counter-clockwise
+    fn signed_area(&self) -> f64 {
+        self.edges().map(|(a, b)| a.0 * b.1 - b.0 * a.1).sum::<f64>() / 2.0
+    }
+
+    /// Each edge as a pair of `f64` end points, closing back to the first vertex
+    fn edges(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        let count = self.vertices.len();
+        (0..count).map(move |i| (as_f64(self.vertices[i]), as_f64(self.vertices[(i + 1) % count])))
+    }
+
+    /// The first pair of edges, by index, that touch where they should not
+    fn find_crossing(&self) -> Option<(usize, usize)> {
+        let edges: Vec<_> = self.edges().collect();
+        let count = edges.len();
+
+        for i in 0..count {
+            for j in (i + 1)..count {
+                let (p1, p2) = edges[i];
+                let (q1, q2) = edges[j];
+                let touches = if j == i + 1 {
+                    // Neighbours share p2 == q1, so they only clash if they fold back along each other
+                    on_segment(q2, p1, p2) || on_segment(p1, q1, q2)
+                } else if i == 0 && j == count - 1 {
+                    // The closing edge shares q2 == p1
+                    on_segment(q1, p1, p2) || on_segment(p2, q1, q2)
+                } else {
+                    segments_touch(p1, p2, q1, q2)
+                };
+                if touches {
+                    return Some((i, j));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn as_f64<T>(point: Point2<T>) -> (f64, f64)
+where T:
+    Scalar
+{
+    (point.x.to_f64(), point.y.to_f64())
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `c`
+fn orientation(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Whether `p` lies on the segment from `a` to `b`, end points included
+fn on_segment(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> bool {
+    orientation(a, b, p) == 0.0
+        && p.0 >= a.0.min(b.0) && p.0 <= a.0.max(b.0)
+        && p.1 >= a.1.min(b.1) && p.1 <= a.1.max(b.1)
+}
+
+/// Whether two segments cross or touch anywhere
+fn segments_touch(p1: (f64, f64), p2: (f64, f64), q1: (f64, f64), q2: (f64, f64)) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+
+    let straddles = |a: f64, b: f64| (a > 0.0 && b < 0.0) || (a < 0.0 && b > 0.0);
+    (straddles(d1, d2) && straddles(d3, d4))
+        || on_segment(p1, q1, q2) || on_segment(p2, q1, q2)
+        || on_segment(q1, p1, p2) || on_segment(q2, p1, p2)
+}
+
+/// Allow a Polygon to return its area, using the shoelace formula
+impl<T> Shape<T> for Polygon<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        self.signed_area().abs()
+    }
+}
+
+/// Allow a Polygon to return its perimeter
+impl<T> Shape2D<T> for Polygon<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        T::from_f64(self.perimeter_f64())
+    }
+
+    fn perimeter_f64(&self) -> f64 {
+        self.edges().map(|(a, b)| (b.0 - a.0).hypot(b.1 - a.1)).sum()
+    }
+}
+
+/// Allow a Polygon to be drawn where its vertices are
+impl<T> Outlined<T> for Polygon<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        let vertices = self.vertices.iter().map(|v| Point2::new(v.x.to_f64(), v.y.to_f64())).collect();
+        Outline::Polygon { vertices }
+    }
+}
+
+/// Allow a Polygon to report the box around its vertices
+impl<T> Bounded<T> for Polygon<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        outline_bounds(&self.outline())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::around(self.vertices.iter().map(|v| Point3::new(v.x, v.y, T::zero()))).unwrap_or_else(|| Aabb::with_size(T::zero(), T::zero(), T::zero()))
+    }
+}
@@ -1,17 +1,13 @@
-use std::{
-    cmp::{PartialEq, PartialOrd}, 
-    fmt::{Debug, Display}, 
-    ops::{Add, Div, Mul, Sub},
+use super::{Bounded, Inset, Mesh, Outline, Projected, Resolution, Shape, Shape3D, ShapeError, Tessellate, View, bounded::centred, error::validate, hollow::check_wall, outline::rectangle_outline, tessellate::box_mesh};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
 };
-use super::Shape;
-use crate::utils::{FromFloat, Ops, ToFloat};
 
 /// Describes a Prism (3D Box)
 pub struct Prism<T> 
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     length: T,
     width: T,
@@ -20,9 +16,7 @@ where T:
 
 impl<T> Prism<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T>
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     pub fn new(length: T, width: T, height: T) -> Self {
         Self {
@@ -32,6 +26,15 @@ where T:
         }
     }
 
+    /// Builds a Prism, rejecting any dimension that is not a positive finite number
+    pub fn try_new(length: T, width: T, height: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(
+            validate("length", length)?,
+            validate("width", width)?,
+            validate("height", height)?,
+        ))
+    }
+
     pub fn length(&self) -> T {
         self.length
     }
@@ -45,21 +48,80 @@ where T:
     }
 }
 
-/// Allow a Prism (Box) to return its area and volume properties
+/// Allow a Prism (Box) to return its surface area
 impl<T> Shape<T> for Prism<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T>
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        let overflow = policy.overflow;
+        let h_w = Ops::mul_with(self.height, self.width, overflow)?;
+        let w_l = Ops::mul_with(self.width, self.length, overflow)?;
+        let h_l = Ops::mul_with(self.height, self.length, overflow)?;
+        let sum = Ops::add_with(Ops::add_with(h_w, w_l, overflow)?, h_l, overflow)?;
+        Ops::double_with(sum, overflow)
+    }
+}
+
+/// Allow a Prism (Box) to return its volume
+impl<T> Shape3D<T> for Prism<T>
+where T:
+    Scalar
 {
-    fn area(&self) -> T {
-        let h_w = self.height * self.width;
-        let w_l = self.width * self.length;
-        let h_l = self.height * self.length;
-        Ops::double(h_w + w_l + h_l)
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        let overflow = policy.overflow;
+        let base = Ops::mul_with(self.length, self.width, overflow)?;
+        Ops::mul_with(base, self.height, overflow)
     }
+}
 
-    fn volume(&self) -> T {
-        self.length * self.width * self.height
+/// Allow a Prism to be hollowed out
+impl<T> Inset<T> for Prism<T>
+where T:
+    Scalar
+{
+    fn inset(&self, thickness: T) -> Result<Self, ShapeError> {
+        let smallest = Ops::min(Ops::min(self.length, self.width), self.height);
+        let wall = Ops::double(check_wall(thickness, smallest)?);
+        Ok(Self::new(self.length - wall, self.width - wall, self.height - wall))
+    }
+}
+
+/// Allow a Prism to be turned into triangles
+impl<T> Tessellate<T> for Prism<T>
+where T:
+    Scalar
+{
+    fn tessellate(&self, _resolution: Resolution) -> Mesh<f64> {
+        box_mesh(self.length.to_f64(), self.width.to_f64(), self.height.to_f64())
     }
-}
\ No newline at end of file
+}
+
+/// Allow a Prism to be drawn, with its length along x, width along y and height along z
+impl<T> Projected<T> for Prism<T>
+where T:
+    Scalar
+{
+    fn silhouette(&self, view: View) -> Outline {
+        let (length, width, height) = (self.length.to_f64(), self.width.to_f64(), self.height.to_f64());
+        match view {
+            View::Front => rectangle_outline(length, height),
+            View::Side => rectangle_outline(width, height),
+            View::Top => rectangle_outline(length, width),
+        }
+    }
+}
+
+/// Allow a Prism to report the box around it, which is the Prism itself
+impl<T> Bounded<T> for Prism<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        centred(self.length.to_f64(), self.width.to_f64(), self.height.to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.length, self.width, self.height)
+    }
+}
@@ -0,0 +1,87 @@
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::centred, error::validate, outline::rectangle_outline};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a Rectangle
+pub struct Rectangle<T>
+where T:
+    Scalar
+{
+    length: T,
+    width: T,
+}
+
+impl<T> Rectangle<T>
+where T:
+    Scalar
+{
+    pub fn new(length: T, width: T) -> Self {
+        Self {
+            length,
+            width,
+        }
+    }
+
+    /// Builds a Rectangle, rejecting any dimension that is not a positive finite number
+    pub fn try_new(length: T, width: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(validate("length", length)?, validate("width", width)?))
+    }
+
+    pub fn length(&self) -> T {
+        self.length
+    }
+
+    pub fn width(&self) -> T {
+        self.width
+    }
+
+    pub fn diagonal(&self) -> T {
+        Ops::hypot(self.length, self.width)
+    }
+}
+
+/// Allow a Rectangle to return its area
+impl<T> Shape<T> for Rectangle<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::mul_with(self.length, self.width, policy.overflow)
+    }
+}
+
+/// Allow a Rectangle to return its perimeter
+impl<T> Shape2D<T> for Rectangle<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        Ops::double(self.length + self.width)
+    }
+}
+
+/// Allow a Rectangle to be drawn around the origin with its length along x
+impl<T> Outlined<T> for Rectangle<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        rectangle_outline(self.length.to_f64(), self.width.to_f64())
+    }
+}
+
+/// Allow a Rectangle to report the box around it, lying flat with its length along x
+impl<T> Bounded<T> for Rectangle<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        centred(self.length.to_f64(), self.width.to_f64(), 0.0)
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.length, self.width, T::zero())
+    }
+}
@@ -0,0 +1,110 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::outline_bounds, error::validate};
+use crate::{
+    geometry::{Aabb, Point2},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a polygon whose sides all have the same length and meet at the same angle
+pub struct RegularPolygon<T>
+where T:
+    Scalar
+{
+    sides: u32,
+    side_length: T,
+}
+
+impl<T> RegularPolygon<T>
+where T:
+    Scalar
+{
+    /// Panics if `sides` is less than three
+    pub fn new(sides: u32, side_length: T) -> Self {
+        assert!(sides >= 3, "a polygon needs at least 3 sides, got {sides}");
+        Self {
+            sides,
+            side_length,
+        }
+    }
+
+    /// Builds a RegularPolygon, rejecting fewer than three sides or a bad side length
+    pub fn try_new(sides: u32, side_length: T) -> Result<Self, ShapeError> {
+        if sides < 3 {
+            return Err(ShapeError::Impossible { reason: "a polygon needs at least 3 sides" });
+        }
+        validate("side_length", side_length).map(|length| Self::new(sides, length))
+    }
+
+    pub fn sides(&self) -> u32 {
+        self.sides
+    }
+
+    pub fn side_length(&self) -> T {
+        self.side_length
+    }
+
+    /// Distance from the centre to the middle of a side
+    pub fn apothem(&self) -> T {
+        T::from_f64(self.side_length.to_f64() / (2.0 * (PI / self.sides as f64).tan()))
+    }
+
+    /// Distance from the centre to a corner
+    pub fn circumradius(&self) -> T {
+        T::from_f64(self.side_length.to_f64() / (2.0 * (PI / self.sides as f64).sin()))
+    }
+}
+
+/// Allow a RegularPolygon to return its area
+impl<T> Shape<T> for RegularPolygon<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let n = self.sides as f64;
+        let side = self.side_length.to_f64();
+        n * side * side / (4.0 * (PI / n).tan())
+    }
+}
+
+/// Allow a RegularPolygon to return its perimeter
+impl<T> Shape2D<T> for RegularPolygon<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        T::from_f64(self.sides as f64) * self.side_length
+    }
+}
+
+/// Allow a RegularPolygon to be drawn around the origin, resting on a flat side
+impl<T> Outlined<T> for RegularPolygon<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        let radius = self.side_length.to_f64() / (2.0 * (PI / self.sides as f64).sin());
+        let start = -PI / 2.0 + PI / self.sides as f64;
+        let vertices = (0..self.sides)
+            .map(|corner| {
+                let angle = start + 2.0 * PI * corner as f64 / self.sides as f64;
+                Point2::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+        Outline::Polygon { vertices }
+    }
+}
+
+/// Allow a RegularPolygon to report the box around it, drawn as by `outline`
+impl<T> Bounded<T> for RegularPolygon<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        outline_bounds(&self.outline())
+    }
+}
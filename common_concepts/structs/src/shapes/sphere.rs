@@ -1,19 +1,15 @@
-use std::{
-    fmt::{Debug, Display},
-    ops::{Add, Sub, Mul, Div},
-    cmp::{PartialEq, PartialOrd},
-    f64::consts::PI,
-};
+use std::f64::consts::PI;
 
-use super::Shape;
-use crate::utils::{FromFloat, ToFloat, Ops};
+use super::{Bounded, Inset, Mesh, Outline, Projected, Resolution, Shape, Shape3D, ShapeError, Tessellate, View, bounded::centred, error::validate, hollow::check_wall, tessellate::sphere_mesh};
+use crate::{
+    geometry::{Aabb, Quaternion},
+    utils::{FromFloat, Ops, PiMultiple, Policy, Scalar},
+};
 
 /// Describes a Sphere
 pub struct Sphere<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     radius: T,
     diameter: T,
@@ -21,9 +17,7 @@ where T:
 
 impl<T> Sphere<T> 
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     pub fn new_with_radius(radius: T) -> Self {
         Self {
@@ -39,6 +33,18 @@ where T:
         }
     }
 
+    /// Builds a Sphere, rejecting a radius that is not a positive finite number
+    pub fn try_new_with_radius(radius: T) -> Result<Self, ShapeError> {
+        validate("radius", radius).map(Self::new_with_radius)
+    }
+
+    /// Builds a Sphere, rejecting a diameter that is not a positive finite number
+    pub fn try_new_with_diameter(diameter: T) -> Result<Self, ShapeError> {
+        let sphere = Self::new_with_diameter(validate("diameter", diameter)?);
+        validate("radius", sphere.radius)?;
+        Ok(sphere)
+    }
+
     pub fn diameter(&self) -> T {
         self.diameter
     }
@@ -47,23 +53,102 @@ where T:
         self.radius
     }
 
+    /// Half the diameter in `f64`, so an odd integer diameter is not truncated like `radius`
+    pub fn radius_f64(&self) -> f64 {
+        self.diameter.to_f64() / 2.0
+    }
+
     pub fn circumference(&self) -> T {
-        T::from_f64(2.0) * T::from_f64(PI) * self.radius
+        T::from_f64(2.0 * PI * self.radius_f64())
+    }
+
+    /// The surface area as an exact multiple of pi, built from the diameter like `Circle::exact_area`
+    pub fn exact_area(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::square(self.diameter), T::one(), 1)
+    }
+
+    /// The volume as an exact multiple of pi, built from the diameter like `Circle::exact_area`
+    pub fn exact_volume(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::cube(self.diameter), T::from_f64(6.0), 1)
     }
 }
 
-/// Allow a Sphere to return its area and volume properties
+/// Allow a Sphere to return its surface area
 impl<T> Shape<T> for Sphere<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.radius_f64();
+        4.0 * PI * radius * radius
+    }
+}
+
+/// Allow a Sphere to return its volume
+impl<T> Shape3D<T> for Sphere<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let radius = self.radius_f64();
+        4.0 / 3.0 * PI * radius * radius * radius
+    }
+}
+
+/// Allow a Sphere to be hollowed out
+impl<T> Inset<T> for Sphere<T>
+where T:
+    Scalar
+{
+    fn inset(&self, thickness: T) -> Result<Self, ShapeError> {
+        let thickness = check_wall(thickness, self.diameter)?;
+        Ok(Self::new_with_radius(self.radius - thickness))
+    }
+}
+
+/// Allow a Sphere to be turned into triangles as a UV sphere or an icosphere
+impl<T> Tessellate<T> for Sphere<T>
+where T:
+    Scalar
+{
+    fn tessellate(&self, resolution: Resolution) -> Mesh<f64> {
+        sphere_mesh(self.radius_f64(), resolution)
+    }
+}
+
+/// Allow a Sphere to be drawn from any side as a circle
+impl<T> Projected<T> for Sphere<T>
+where T:
+    Scalar
 {
-    fn area(&self) -> T {
-        T::from_f64(4.0) * FromFloat::from_f64(PI) * Ops::<T>::square(self.radius)
+    fn silhouette(&self, _view: View) -> Outline {
+        Outline::Circle { radius: self.radius_f64() }
     }
+}
 
-    fn volume(&self) -> T {
-        T::from_f64(4.0/3.0) * FromFloat::from_f64(PI) * Ops::<T>::cube(self.radius)
+/// Allow a Sphere to report the box around it, which turning does not change
+impl<T> Bounded<T> for Sphere<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let diameter = self.diameter.to_f64();
+        centred(diameter, diameter, diameter)
     }
-}
\ No newline at end of file
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.diameter, self.diameter, self.diameter)
+    }
+
+    fn rotated_bounds(&self, _rotation: Quaternion) -> Aabb<f64> {
+        self.local_bounds()
+    }
+}
@@ -0,0 +1,112 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, Sphere, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes the part of a Sphere cut off by a single plane
+///
+/// Also models a round tank filled to `height` from the bottom.
+pub struct SphericalCap<T>
+where T:
+    Scalar
+{
+    sphere: Sphere<T>,
+    height: T,
+}
+
+impl<T> SphericalCap<T>
+where T:
+    Scalar
+{
+    /// `height` is measured from the plane to the pole of the sphere
+    pub fn new(sphere: Sphere<T>, height: T) -> Self {
+        Self {
+            sphere,
+            height,
+        }
+    }
+
+    /// Builds a SphericalCap, rejecting a height outside the sphere
+    pub fn try_new(sphere: Sphere<T>, height: T) -> Result<Self, ShapeError> {
+        let height = validate("height", height)?;
+        if height > sphere.diameter() {
+            return Err(ShapeError::Impossible { reason: "a cap cannot be taller than the sphere's diameter" });
+        }
+        Ok(Self::new(sphere, height))
+    }
+
+    pub fn sphere(&self) -> &Sphere<T> {
+        &self.sphere
+    }
+
+    pub fn height(&self) -> T {
+        self.height
+    }
+
+    /// Radius of the flat circle where the plane cuts the sphere
+    pub fn base_radius(&self) -> T {
+        T::from_f64(cut_radius(self.sphere.radius().to_f64(), self.height.to_f64()))
+    }
+
+    /// The area of the rounded part, without the flat base
+    pub fn curved_area(&self) -> T {
+        T::from_f64(2.0 * PI * self.sphere.radius().to_f64() * self.height.to_f64())
+    }
+}
+
+/// Radius of the circle cut from a sphere of `radius` at `height` above its bottom
+pub(crate) fn cut_radius(radius: f64, height: f64) -> f64 {
+    (height * (2.0 * radius - height)).max(0.0).sqrt()
+}
+
+/// Volume of a sphere of `radius` below `height` from its bottom
+pub(crate) fn volume_below(radius: f64, height: f64) -> f64 {
+    PI * height * height * (3.0 * radius - height) / 3.0
+}
+
+/// Allow a SphericalCap to return its total surface area, including the flat base
+impl<T> Shape<T> for SphericalCap<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.sphere.radius().to_f64();
+        let height = self.height.to_f64();
+        let base = cut_radius(radius, height);
+        2.0 * PI * radius * height + PI * base * base
+    }
+}
+
+/// Allow a SphericalCap to return its volume
+impl<T> Shape3D<T> for SphericalCap<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        volume_below(self.sphere.radius().to_f64(), self.height.to_f64())
+    }
+}
+
+/// Allow a SphericalCap resting on its base to report the box around it
+impl<T> Bounded<T> for SphericalCap<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let (radius, height) = (self.sphere.radius().to_f64(), self.height.to_f64());
+        // A cap taller than the radius is as wide as the whole sphere
+        let width = if height > radius { 2.0 * radius } else { 2.0 * cut_radius(radius, height) };
+        centred(width, width, height)
+    }
+}
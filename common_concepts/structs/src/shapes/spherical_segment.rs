@@ -0,0 +1,137 @@
+use std::f64::consts::PI;
+
+use super::{
+    Bounded, Shape, Shape3D, ShapeError, Sphere,
+    bounded::centred,
+    spherical_cap::{cut_radius, volume_below},
+};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes the slice of a Sphere between two parallel planes
+pub struct SphericalSegment<T>
+where T:
+    Scalar
+{
+    sphere: Sphere<T>,
+    lower: T,
+    upper: T,
+}
+
+impl<T> SphericalSegment<T>
+where T:
+    Scalar
+{
+    /// `lower` and `upper` are the heights of the two planes above the bottom of the sphere
+    pub fn new(sphere: Sphere<T>, lower: T, upper: T) -> Self {
+        Self {
+            sphere,
+            lower: Ops::min(lower, upper),
+            upper: Ops::max(lower, upper),
+        }
+    }
+
+    /// Builds a SphericalSegment, rejecting planes outside the sphere or on top of each other
+    pub fn try_new(sphere: Sphere<T>, lower: T, upper: T) -> Result<Self, ShapeError> {
+        let segment = Self::new(sphere, lower, upper);
+        for value in [segment.lower, segment.upper] {
+            let float = value.to_f64();
+            if !float.is_finite() {
+                return Err(ShapeError::NonFinite { dimension: "height", value: float });
+            }
+        }
+        if segment.lower < T::zero() || segment.upper > segment.sphere.diameter() {
+            return Err(ShapeError::Impossible { reason: "both planes must cut through the sphere" });
+        }
+        if segment.lower == segment.upper {
+            return Err(ShapeError::Degenerate { dimension: "height" });
+        }
+        Ok(segment)
+    }
+
+    pub fn sphere(&self) -> &Sphere<T> {
+        &self.sphere
+    }
+
+    pub fn lower(&self) -> T {
+        self.lower
+    }
+
+    pub fn upper(&self) -> T {
+        self.upper
+    }
+
+    pub fn height(&self) -> T {
+        self.upper - self.lower
+    }
+
+    /// Radii of the flat circles at the lower and upper planes
+    pub fn base_radii(&self) -> (T, T) {
+        let radius = self.sphere.radius().to_f64();
+        (
+            T::from_f64(cut_radius(radius, self.lower.to_f64())),
+            T::from_f64(cut_radius(radius, self.upper.to_f64())),
+        )
+    }
+
+    /// The area of the rounded band, without the flat ends
+    pub fn curved_area(&self) -> T {
+        T::from_f64(self.curved_area_f64())
+    }
+
+    fn curved_area_f64(&self) -> f64 {
+        2.0 * PI * self.sphere.radius().to_f64() * (self.upper.to_f64() - self.lower.to_f64())
+    }
+}
+
+/// Allow a SphericalSegment to return its total surface area, including both flat ends
+impl<T> Shape<T> for SphericalSegment<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let radius = self.sphere.radius().to_f64();
+        let lower = cut_radius(radius, self.lower.to_f64());
+        let upper = cut_radius(radius, self.upper.to_f64());
+        self.curved_area_f64() + PI * (lower * lower + upper * upper)
+    }
+}
+
+/// Allow a SphericalSegment to return its volume
+impl<T> Shape3D<T> for SphericalSegment<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let radius = self.sphere.radius().to_f64();
+        volume_below(radius, self.upper.to_f64()) - volume_below(radius, self.lower.to_f64())
+    }
+}
+
+/// Allow a SphericalSegment resting on its lower plane to report the box around it
+impl<T> Bounded<T> for SphericalSegment<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let radius = self.sphere.radius().to_f64();
+        let (lower, upper) = (self.lower.to_f64(), self.upper.to_f64());
+        // A slice through the equator is as wide as the whole sphere
+        let width = if lower <= radius && radius <= upper {
+            2.0 * radius
+        } else {
+            2.0 * cut_radius(radius, lower).max(cut_radius(radius, upper))
+        };
+        centred(width, width, upper - lower)
+    }
+}
@@ -0,0 +1,113 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, Sphere, error::validate};
+use crate::{
+    geometry::{Aabb, Quaternion},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a hollow ball, an outer Sphere with a smaller one removed from its centre
+pub struct SphericalShell<T>
+where T:
+    Scalar
+{
+    outer: Sphere<T>,
+    inner_radius: T,
+}
+
+impl<T> SphericalShell<T>
+where T:
+    Scalar
+{
+    pub fn new(outer: Sphere<T>, inner_radius: T) -> Self {
+        Self {
+            outer,
+            inner_radius,
+        }
+    }
+
+    /// Builds a SphericalShell, rejecting an inner radius that does not fit inside the outer sphere
+    pub fn try_new(outer: Sphere<T>, inner_radius: T) -> Result<Self, ShapeError> {
+        let inner_radius = validate("inner_radius", inner_radius)?;
+        if inner_radius >= outer.radius() {
+            return Err(ShapeError::Impossible { reason: "the inner radius must be smaller than the outer radius" });
+        }
+        Ok(Self::new(outer, inner_radius))
+    }
+
+    pub fn outer(&self) -> &Sphere<T> {
+        &self.outer
+    }
+
+    pub fn inner_radius(&self) -> T {
+        self.inner_radius
+    }
+
+    pub fn thickness(&self) -> T {
+        self.outer.radius() - self.inner_radius
+    }
+
+    pub fn outer_area(&self) -> T {
+        self.outer.area()
+    }
+
+    pub fn inner_area(&self) -> T {
+        let inner = self.inner_radius.to_f64();
+        T::from_f64(4.0 * PI * inner * inner)
+    }
+
+    /// Both faces of a shell are curved, so this is the same as the total surface area
+    pub fn curved_area(&self) -> T {
+        self.area()
+    }
+}
+
+/// Allow a SphericalShell to return its surface area, inside and out
+impl<T> Shape<T> for SphericalShell<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let outer = self.outer.radius().to_f64();
+        let inner = self.inner_radius.to_f64();
+        4.0 * PI * (outer * outer + inner * inner)
+    }
+}
+
+/// Allow a SphericalShell to return the volume of material between its faces
+impl<T> Shape3D<T> for SphericalShell<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let outer = self.outer.radius().to_f64();
+        let inner = self.inner_radius.to_f64();
+        4.0 / 3.0 * PI * (outer.powi(3) - inner.powi(3))
+    }
+}
+
+/// Allow a SphericalShell to report the box around its outer sphere
+impl<T> Bounded<T> for SphericalShell<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        self.outer.local_bounds()
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        self.outer.bounding_box()
+    }
+
+    fn rotated_bounds(&self, rotation: Quaternion) -> Aabb<f64> {
+        self.outer.rotated_bounds(rotation)
+    }
+}
@@ -0,0 +1,81 @@
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::centred, error::validate, outline::rectangle_outline};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a Square
+pub struct Square<T>
+where T:
+    Scalar
+{
+    side: T,
+}
+
+impl<T> Square<T>
+where T:
+    Scalar
+{
+    pub fn new(side: T) -> Self {
+        Self {
+            side
+        }
+    }
+
+    /// Builds a Square, rejecting a side that is not a positive finite number
+    pub fn try_new(side: T) -> Result<Self, ShapeError> {
+        validate("side", side).map(Self::new)
+    }
+
+    pub fn side(&self) -> T {
+        self.side
+    }
+
+    pub fn diagonal(&self) -> T {
+        Ops::hypot(self.side, self.side)
+    }
+}
+
+/// Allow a Square to return its area
+impl<T> Shape<T> for Square<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::square_with(self.side, policy.overflow)
+    }
+}
+
+/// Allow a Square to return its perimeter
+impl<T> Shape2D<T> for Square<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        Ops::double(Ops::double(self.side))
+    }
+}
+
+/// Allow a Square to be drawn around the origin
+impl<T> Outlined<T> for Square<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        rectangle_outline(self.side.to_f64(), self.side.to_f64())
+    }
+}
+
+/// Allow a Square to report the box around it, lying flat
+impl<T> Bounded<T> for Square<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        centred(self.side.to_f64(), self.side.to_f64(), 0.0)
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.side, self.side, T::zero())
+    }
+}
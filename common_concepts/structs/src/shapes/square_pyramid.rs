@@ -0,0 +1,103 @@
+use super::{Bounded, Shape, Shape3D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a right Pyramid with a square base
+pub struct SquarePyramid<T>
+where T:
+    Scalar
+{
+    base: T,
+    height: T,
+}
+
+impl<T> SquarePyramid<T>
+where T:
+    Scalar
+{
+    pub fn new(base: T, height: T) -> Self {
+        Self {
+            base,
+            height,
+        }
+    }
+
+    /// Builds a SquarePyramid from the height of each triangular face instead of the apex
+    ///
+    /// Panics if the slant height is not taller than half the base.
+    pub fn new_with_slant_height(base: T, slant_height: T) -> Self {
+        let half = Ops::half(base.to_f64());
+        let rise = slant_height.to_f64() * slant_height.to_f64() - half * half;
+        assert!(rise > 0.0, "slant height {slant_height} is too short for a base of {base}");
+        Self::new(base, T::from_f64(rise.sqrt()))
+    }
+
+    /// Builds a SquarePyramid, rejecting any dimension that is not a positive finite number
+    pub fn try_new(base: T, height: T) -> Result<Self, ShapeError> {
+        Ok(Self::new(validate("base", base)?, validate("height", height)?))
+    }
+
+    pub fn base(&self) -> T {
+        self.base
+    }
+
+    pub fn height(&self) -> T {
+        self.height
+    }
+
+    /// Height of each triangular face, from the middle of a base edge to the apex
+    pub fn slant_height(&self) -> T {
+        T::from_f64(self.slant_height_f64())
+    }
+
+    fn slant_height_f64(&self) -> f64 {
+        Ops::half(self.base.to_f64()).hypot(self.height.to_f64())
+    }
+}
+
+/// Allow a SquarePyramid to return its surface area
+impl<T> Shape<T> for SquarePyramid<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        let base = self.base.to_f64();
+        base * base + 2.0 * base * self.slant_height_f64()
+    }
+}
+
+/// Allow a SquarePyramid to return its volume
+impl<T> Shape3D<T> for SquarePyramid<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let base = self.base.to_f64();
+        base * base * self.height.to_f64() / 3.0
+    }
+}
+
+/// Allow a SquarePyramid standing on the z axis to report the box around it
+impl<T> Bounded<T> for SquarePyramid<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let base = self.base.to_f64();
+        centred(base, base, self.height.to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        Aabb::with_size(self.base, self.base, self.height)
+    }
+}
@@ -0,0 +1,172 @@
+use std::{collections::HashMap, f64::consts::PI};
+
+use super::{Mesh, Shape3D};
+use crate::{geometry::Point3, utils::Scalar};
+
+/// How finely a round surface is divided into triangles
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Resolution {
+    /// Bands of latitude and slices of longitude, like the lines on a globe
+    Uv { rings: u32, segments: u32 },
+    /// How many times each face of an icosahedron is split into four, giving evenly sized triangles
+    ///
+    /// Levels above `Resolution::MAX_SUBDIVISIONS` are treated as that level.
+    Icosphere { subdivisions: u32 },
+}
+
+impl Resolution {
+    /// The finest icosphere level, which already has over a million triangles
+    pub const MAX_SUBDIVISIONS: u32 = 8;
+
+    /// The number of slices around a circular edge
+    ///
+    /// An icosphere level is treated as the matching number of slices around its equator.
+    pub fn segments(&self) -> u32 {
+        match *self {
+            Self::Uv { segments, .. } => segments,
+            Self::Icosphere { subdivisions } => 5 << subdivisions.min(Self::MAX_SUBDIVISIONS),
+        }
+    }
+}
+
+/// A solid that can be approximated by a triangle Mesh, such as for export to a CAD program
+pub trait Tessellate<T>: Shape3D<T>
+where T:
+    Scalar
+{
+    /// Covers the surface in outward facing triangles, centred on the origin
+    ///
+    /// Flat faced solids are reproduced exactly and ignore the resolution.
+    fn tessellate(&self, resolution: Resolution) -> Mesh<f64>;
+}
+
+/// A box centred on the origin, split into two triangles per face
+pub(crate) fn box_mesh(length: f64, width: f64, height: f64) -> Mesh<f64> {
+    let (x, y, z) = (length / 2.0, width / 2.0, height / 2.0);
+    let vertices = vec![
+        Point3::new(-x, -y, -z), Point3::new(x, -y, -z), Point3::new(x, y, -z), Point3::new(-x, y, -z),
+        Point3::new(-x, -y, z), Point3::new(x, -y, z), Point3::new(x, y, z), Point3::new(-x, y, z),
+    ];
+    let triangles = vec![
+        [0, 2, 1], [0, 3, 2], [4, 5, 6], [4, 6, 7], [0, 1, 5], [0, 5, 4],
+        [3, 7, 6], [3, 6, 2], [0, 4, 7], [0, 7, 3], [1, 2, 6], [1, 6, 5],
+    ];
+    Mesh::new(vertices, triangles)
+}
+
+/// A sphere of the given radius centred on the origin
+///
+/// Panics if a UV resolution has fewer than 2 rings or 3 segments.
+pub(crate) fn sphere_mesh(radius: f64, resolution: Resolution) -> Mesh<f64> {
+    match resolution {
+        Resolution::Uv { rings, segments } => uv_sphere(radius, rings, segments),
+        Resolution::Icosphere { subdivisions } => icosphere(radius, subdivisions),
+    }
+}
+
+fn uv_sphere(radius: f64, rings: u32, segments: u32) -> Mesh<f64> {
+    assert!(rings >= 2 && segments >= 3, "a UV sphere needs at least 2 rings and 3 segments");
+    let (rings, segments) = (rings as usize, segments as usize);
+
+    let mut vertices = vec![Point3::new(0.0, 0.0, radius)];
+    for ring in 1..rings {
+        let polar = PI * ring as f64 / rings as f64;
+        for segment in 0..segments {
+            let azimuth = 2.0 * PI * segment as f64 / segments as f64;
+            vertices.push(Point3::new(
+                radius * polar.sin() * azimuth.cos(),
+                radius * polar.sin() * azimuth.sin(),
+                radius * polar.cos(),
+            ));
+        }
+    }
+    let south = vertices.len();
+    vertices.push(Point3::new(0.0, 0.0, -radius));
+
+    let at = |ring: usize, segment: usize| 1 + (ring - 1) * segments + segment % segments;
+    let mut triangles = Vec::new();
+    for segment in 0..segments {
+        triangles.push([0, at(1, segment), at(1, segment + 1)]);
+        for ring in 1..rings - 1 {
+            let (a, b) = (at(ring, segment), at(ring, segment + 1));
+            let (c, d) = (at(ring + 1, segment), at(ring + 1, segment + 1));
+            triangles.push([a, c, d]);
+            triangles.push([a, d, b]);
+        }
+        triangles.push([at(rings - 1, segment), south, at(rings - 1, segment + 1)]);
+    }
+    Mesh::new(vertices, triangles)
+}
+
+fn icosphere(radius: f64, subdivisions: u32) -> Mesh<f64> {
+    let t = (1.0 + 5.0_f64.sqrt()) / 2.0;
+    let mut corners = vec![
+        [-1.0, t, 0.0], [1.0, t, 0.0], [-1.0, -t, 0.0], [1.0, -t, 0.0],
+        [0.0, -1.0, t], [0.0, 1.0, t], [0.0, -1.0, -t], [0.0, 1.0, -t],
+        [t, 0.0, -1.0], [t, 0.0, 1.0], [-t, 0.0, -1.0], [-t, 0.0, 1.0],
+    ];
+    let mut triangles = vec![
+        [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+        [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+        [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+        [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1],
+    ];
+
+    for _ in 0..subdivisions.min(Resolution::MAX_SUBDIVISIONS) {
+        // Neighbouring triangles must share the midpoint of their common edge
+        let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut midpoint = |a: usize, b: usize, corners: &mut Vec<[f64; 3]>| {
+            *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                let (p, q): ([f64; 3], [f64; 3]) = (corners[a], corners[b]);
+                corners.push([(p[0] + q[0]) / 2.0, (p[1] + q[1]) / 2.0, (p[2] + q[2]) / 2.0]);
+                corners.len() - 1
+            })
+        };
+        triangles = triangles
+            .into_iter()
+            .flat_map(|[a, b, c]| {
+                let ab = midpoint(a, b, &mut corners);
+                let bc = midpoint(b, c, &mut corners);
+                let ca = midpoint(c, a, &mut corners);
+                [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+            })
+            .collect();
+    }
+
+    let vertices = corners
+        .into_iter()
+        .map(|[x, y, z]| {
+            let scale = radius / (x * x + y * y + z * z).sqrt();
+            Point3::new(x * scale, y * scale, z * scale)
+        })
+        .collect();
+    Mesh::new(vertices, triangles)
+}
+
+/// A cylinder standing on the z axis, centred on the origin, with its sides cut into flat slices
+///
+/// Panics if there are fewer than 3 segments.
+pub(crate) fn cylinder_mesh(radius: f64, height: f64, segments: u32) -> Mesh<f64> {
+    assert!(segments >= 3, "a cylinder needs at least 3 segments");
+    let segments = segments as usize;
+    let z = height / 2.0;
+
+    let mut vertices = vec![Point3::new(0.0, 0.0, -z), Point3::new(0.0, 0.0, z)];
+    for level in [-z, z] {
+        for segment in 0..segments {
+            let azimuth = 2.0 * PI * segment as f64 / segments as f64;
+            vertices.push(Point3::new(radius * azimuth.cos(), radius * azimuth.sin(), level));
+        }
+    }
+
+    let bottom = |segment: usize| 2 + segment % segments;
+    let top = |segment: usize| 2 + segments + segment % segments;
+    let mut triangles = Vec::new();
+    for segment in 0..segments {
+        triangles.push([0, bottom(segment + 1), bottom(segment)]);
+        triangles.push([1, top(segment), top(segment + 1)]);
+        triangles.push([top(segment), bottom(segment), bottom(segment + 1)]);
+        triangles.push([top(segment), bottom(segment + 1), top(segment + 1)]);
+    }
+    Mesh::new(vertices, triangles)
+}
@@ -0,0 +1,107 @@
+use std::f64::consts::PI;
+
+use super::{Bounded, Shape, Shape3D, ShapeError, bounded::centred, error::validate};
+use crate::{
+    geometry::Aabb,
+    utils::{Ops, PiMultiple, Policy, Scalar},
+};
+
+/// Describes a ring shaped Torus
+pub struct Torus<T>
+where T:
+    Scalar
+{
+    major_radius: T,
+    minor_radius: T,
+}
+
+impl<T> Torus<T>
+where T:
+    Scalar
+{
+    /// `major_radius` runs from the centre of the hole to the centre of the tube,
+    /// `minor_radius` is the radius of the tube itself
+    pub fn new(major_radius: T, minor_radius: T) -> Self {
+        Self {
+            major_radius,
+            minor_radius,
+        }
+    }
+
+    pub fn new_with_diameters(major_diameter: T, minor_diameter: T) -> Self {
+        Self::new(Ops::half(major_diameter), Ops::half(minor_diameter))
+    }
+
+    /// Builds a Torus, rejecting bad radii or a tube too thick to leave a hole
+    pub fn try_new(major_radius: T, minor_radius: T) -> Result<Self, ShapeError> {
+        let torus = Self::new(validate("major_radius", major_radius)?, validate("minor_radius", minor_radius)?);
+        if minor_radius > major_radius {
+            return Err(ShapeError::Impossible { reason: "the tube of a torus must not be wider than its major radius" });
+        }
+        Ok(torus)
+    }
+
+    pub fn major_radius(&self) -> T {
+        self.major_radius
+    }
+
+    pub fn minor_radius(&self) -> T {
+        self.minor_radius
+    }
+
+    /// The surface area as an exact multiple of pi squared
+    pub fn exact_area(&self) -> PiMultiple<T> {
+        let four = T::from_f64(4.0);
+        PiMultiple::new(four * self.major_radius * self.minor_radius, T::one(), 2)
+    }
+
+    /// The volume as an exact multiple of pi squared
+    pub fn exact_volume(&self) -> PiMultiple<T> {
+        PiMultiple::new(Ops::double(self.major_radius) * Ops::square(self.minor_radius), T::one(), 2)
+    }
+}
+
+/// Allow a Torus to return its surface area
+impl<T> Shape<T> for Torus<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        4.0 * PI * PI * self.major_radius.to_f64() * self.minor_radius.to_f64()
+    }
+}
+
+/// Allow a Torus to return its volume
+impl<T> Shape3D<T> for Torus<T>
+where T:
+    Scalar
+{
+    fn volume_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.volume_f64(), policy)
+    }
+
+    fn volume_f64(&self) -> f64 {
+        let minor = self.minor_radius.to_f64();
+        2.0 * PI * PI * self.major_radius.to_f64() * minor * minor
+    }
+}
+
+/// Allow a Torus lying flat in the xy plane to report the box around it
+impl<T> Bounded<T> for Torus<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        let width = 2.0 * (self.major_radius.to_f64() + self.minor_radius.to_f64());
+        centred(width, width, 2.0 * self.minor_radius.to_f64())
+    }
+
+    fn bounding_box(&self) -> Aabb<T> {
+        let width = Ops::double(self.major_radius + self.minor_radius);
+        Aabb::with_size(width, width, Ops::double(self.minor_radius))
+    }
+}
@@ -0,0 +1,140 @@
+use super::{Bounded, Outline, Outlined, Shape, Shape2D, ShapeError, bounded::outline_bounds, error::validate};
+use crate::{
+    geometry::{Aabb, Point2},
+    utils::{Ops, Policy, Scalar},
+};
+
+/// Describes a Triangle by the lengths of its three sides
+pub struct Triangle<T>
+where T:
+    Scalar
+{
+    a: T,
+    b: T,
+    c: T,
+}
+
+impl<T> Triangle<T>
+where T:
+    Scalar
+{
+    pub fn new_with_sides(a: T, b: T, c: T) -> Self {
+        Self {
+            a,
+            b,
+            c,
+        }
+    }
+
+    /// Builds a right triangle whose legs are the base and the height
+    ///
+    /// The hypotenuse is rounded for integer types.
+    pub fn new_with_base_height(base: T, height: T) -> Self {
+        Self::new_with_sides(base, height, Ops::hypot(base, height))
+    }
+
+    /// Builds a Triangle from the positions of its corners
+    ///
+    /// The side lengths are rounded for integer types.
+    pub fn new_with_coordinates(p1: Point2<T>, p2: Point2<T>, p3: Point2<T>) -> Self {
+        Self::new_with_sides(p2.distance(&p3), p1.distance(&p3), p1.distance(&p2))
+    }
+
+    /// Builds a Triangle, rejecting sides that are not positive or cannot meet
+    pub fn try_new_with_sides(a: T, b: T, c: T) -> Result<Self, ShapeError> {
+        let triangle = Self::new_with_sides(validate("a", a)?, validate("b", b)?, validate("c", c)?);
+        let [shortest, middle, longest] = triangle.sorted_sides();
+
+        if shortest + middle < longest {
+            Err(ShapeError::Impossible { reason: "the two shorter sides of a triangle must reach the longest" })
+        } else if shortest + middle == longest {
+            Err(ShapeError::Degenerate { dimension: "area" })
+        } else {
+            Ok(triangle)
+        }
+    }
+
+    /// Builds a right triangle, rejecting a base or height that is not a positive finite number
+    pub fn try_new_with_base_height(base: T, height: T) -> Result<Self, ShapeError> {
+        Ok(Self::new_with_base_height(validate("base", base)?, validate("height", height)?))
+    }
+
+    /// Builds a Triangle from its corners, rejecting corners that lie on one line
+    pub fn try_new_with_coordinates(p1: Point2<T>, p2: Point2<T>, p3: Point2<T>) -> Result<Self, ShapeError> {
+        for value in [p1.x, p1.y, p2.x, p2.y, p3.x, p3.y] {
+            let float = value.to_f64();
+            if !float.is_finite() {
+                return Err(ShapeError::NonFinite { dimension: "coordinate", value: float });
+            }
+        }
+        // Compare the two halves of the cross product so unsigned types cannot underflow
+        let lhs = (p2.x.to_f64() - p1.x.to_f64()) * (p3.y.to_f64() - p1.y.to_f64());
+        let rhs = (p2.y.to_f64() - p1.y.to_f64()) * (p3.x.to_f64() - p1.x.to_f64());
+        if lhs == rhs {
+            return Err(ShapeError::Degenerate { dimension: "area" });
+        }
+        Ok(Self::new_with_coordinates(p1, p2, p3))
+    }
+
+    pub fn sides(&self) -> [T; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    fn sorted_sides(&self) -> [T; 3] {
+        let mut sides = self.sides();
+        sides.sort_by(|x, y| x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal));
+        sides
+    }
+}
+
+/// Allow a Triangle to return its area, using Heron's formula
+impl<T> Shape<T> for Triangle<T>
+where T:
+    Scalar
+{
+    fn area_with(&self, policy: Policy) -> Option<T> {
+        Ops::from_f64_with(self.area_f64(), policy)
+    }
+
+    fn area_f64(&self) -> f64 {
+        // The sorted form of Heron's formula stays accurate for needle-thin triangles
+        let [c, b, a] = self.sorted_sides().map(|side| side.to_f64());
+        let product = (a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c));
+        0.25 * product.max(0.0).sqrt()
+    }
+}
+
+/// Allow a Triangle to return its perimeter
+impl<T> Shape2D<T> for Triangle<T>
+where T:
+    Scalar
+{
+    fn perimeter(&self) -> T {
+        self.a + self.b + self.c
+    }
+}
+
+/// Allow a Triangle to be drawn with side `c` along the bottom and its centroid on the origin
+impl<T> Outlined<T> for Triangle<T>
+where T:
+    Scalar
+{
+    fn outline(&self) -> Outline {
+        let [a, b, c] = self.sides().map(|side| side.to_f64());
+        // Place side c on the x axis and find the apex from the law of cosines
+        let x = (b * b + c * c - a * a) / (2.0 * c);
+        let y = (b * b - x * x).max(0.0).sqrt();
+        let (cx, cy) = ((c + x) / 3.0, y / 3.0);
+        Outline::Polygon { vertices: vec![Point2::new(-cx, -cy), Point2::new(c - cx, -cy), Point2::new(x - cx, y - cy)] }
+    }
+}
+
+/// Allow a Triangle to report the box around it, drawn as by `outline`
+impl<T> Bounded<T> for Triangle<T>
+where T:
+    Scalar
+{
+    fn local_bounds(&self) -> Aabb<f64> {
+        outline_bounds(&self.outline())
+    }
+}
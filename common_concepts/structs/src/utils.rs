@@ -1,3 +1,11 @@
 pub mod math;
+pub mod scalar;
+pub mod rational;
+pub mod pi;
+pub mod fixed;
 
-pub use math::{FromFloat, ToFloat, Ops};
\ No newline at end of file
+pub use math::{FromFloat, ToFloat, Ops, Overflow, Policy, Rounding};
+pub use scalar::Scalar;
+pub use rational::{Integer, Rational};
+pub use pi::PiMultiple;
+pub use fixed::Fixed;
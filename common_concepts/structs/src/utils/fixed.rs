@@ -0,0 +1,165 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use super::{FromFloat, Rounding, Scalar, ToFloat};
+
+/// A decimal number with exactly `SCALE` digits after the point
+///
+/// Stored as an `i128` count of `10^-SCALE` units, so sums are exact and products and
+/// quotients round half away from zero to the same digits on every machine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const SCALE: u32> {
+    raw: i128,
+}
+
+impl<const SCALE: u32> Fixed<SCALE> {
+    /// The number of raw units in one
+    pub const FACTOR: i128 = 10_i128.pow(SCALE);
+
+    /// Builds a value from a count of `10^-SCALE` units, so `Fixed::<2>::from_raw(150)` is `1.50`
+    pub fn from_raw(raw: i128) -> Self {
+        Self {
+            raw,
+        }
+    }
+
+    pub fn from_integer(value: i64) -> Self {
+        Self::from_raw(value as i128 * Self::FACTOR)
+    }
+
+    pub fn raw(&self) -> i128 {
+        self.raw
+    }
+}
+
+/// Divides and rounds half away from zero, `None` if `d` is zero
+fn div_round(n: i128, d: i128) -> Option<i128> {
+    let quotient = n.checked_div(d)?;
+    let remainder = n % d;
+    if remainder.unsigned_abs() * 2 >= d.unsigned_abs() {
+        let step = if (n < 0) == (d < 0) { 1 } else { -1 };
+        quotient.checked_add(step)
+    } else {
+        Some(quotient)
+    }
+}
+
+impl<const SCALE: u32> Display for Fixed<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let factor = Self::FACTOR.unsigned_abs();
+        let whole = self.raw.unsigned_abs() / factor;
+        let fraction = self.raw.unsigned_abs() % factor;
+
+        if SCALE == 0 {
+            write!(f, "{sign}{whole}")
+        } else {
+            write!(f, "{sign}{whole}.{fraction:0width$}", width = SCALE as usize)
+        }
+    }
+}
+
+impl<const SCALE: u32> Add for Fixed<SCALE> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw + rhs.raw)
+    }
+}
+
+impl<const SCALE: u32> Sub for Fixed<SCALE> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw - rhs.raw)
+    }
+}
+
+impl<const SCALE: u32> Mul for Fixed<SCALE> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl<const SCALE: u32> Div for Fixed<SCALE> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.raw != 0, "attempt to divide by zero");
+        let scaled = self.raw.checked_mul(Self::FACTOR).expect("attempt to divide with overflow");
+        Self::from_raw(div_round(scaled, rhs.raw).expect("attempt to divide with overflow"))
+    }
+}
+
+impl<const SCALE: u32> ToFloat for Fixed<SCALE> {
+    fn to_f64(self) -> f64 {
+        self.raw as f64 / Self::FACTOR as f64
+    }
+}
+
+/// The rounding mode decides what happens to digits beyond `SCALE`
+impl<const SCALE: u32> FromFloat for Fixed<SCALE> {
+    fn from_f64_rounded(f: f64, rounding: Rounding) -> Self {
+        Self::from_raw(i128::from_f64_rounded(f * Self::FACTOR as f64, rounding))
+    }
+
+    fn checked_from_f64(f: f64, rounding: Rounding) -> Option<Self> {
+        i128::checked_from_f64(f * Self::FACTOR as f64, rounding).map(Self::from_raw)
+    }
+}
+
+impl<const SCALE: u32> Scalar for Fixed<SCALE> {
+    fn zero() -> Self {
+        Self::from_raw(0)
+    }
+
+    fn one() -> Self {
+        Self::from_raw(Self::FACTOR)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_add(rhs.raw).map(Self::from_raw)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.raw.checked_sub(rhs.raw).map(Self::from_raw)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        div_round(self.raw.checked_mul(rhs.raw)?, Self::FACTOR).map(Self::from_raw)
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw.saturating_add(rhs.raw))
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw.saturating_sub(rhs.raw))
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or_else(|| {
+            let raw = if (self.raw < 0) == (rhs.raw < 0) { i128::MAX } else { i128::MIN };
+            Self::from_raw(raw)
+        })
+    }
+
+    fn wrapping_add(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw.wrapping_add(rhs.raw))
+    }
+
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::from_raw(self.raw.wrapping_sub(rhs.raw))
+    }
+
+    /// Wraps the raw product, then rounds it like `mul` so every policy agrees on the last digit
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        // Dividing by FACTOR leaves room for the rounding step, so this cannot fail
+        let raw = div_round(self.raw.wrapping_mul(rhs.raw), Self::FACTOR).expect("FACTOR is positive");
+        Self::from_raw(raw)
+    }
+}
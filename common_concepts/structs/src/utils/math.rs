@@ -1,27 +1,36 @@
-use std::{
-    cmp::{PartialEq, PartialOrd}, fmt::{Debug, Display}, marker::PhantomData, ops::{Add, Div, Mul, Sub}
-};
+use std::marker::PhantomData;
+
+use super::Scalar;
+
+/// How integer arithmetic behaves when a result does not fit in the type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Report the overflow as `None`
+    #[default]
+    Checked,
+    /// Clamp the result to the type's bounds
+    Saturating,
+    /// Wrap the result around the type's bounds
+    Wrapping,
+}
 
 pub struct Ops<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
+    Scalar
 {
     phantom: PhantomData<T>,
 }
 
 impl<T> Ops<T>
 where T:
-    Clone + Copy + Debug + Display + Add<Output=T> + Sub<Output=T> 
-    + Mul<Output=T> + Div<Output=T> + PartialEq + PartialOrd
-    + FromFloat + ToFloat
+    Scalar
 {
     pub fn square(prop: T) -> T {
         prop * prop
     }
 
     pub fn half(value: T) -> T {
-        value / FromFloat::from_f64(2.0)
+        value / (T::one() + T::one())
     }
 
     pub fn cube(value: T) -> T {
@@ -29,86 +38,326 @@ where T:
     }
 
     pub fn double(value: T) -> T {
-        value * FromFloat::from_f64(2.0)
+        value + value
+    }
+
+    /// Adds two values under the given overflow policy
+    pub fn add_with(a: T, b: T, overflow: Overflow) -> Option<T> {
+        match overflow {
+            Overflow::Checked => a.checked_add(b),
+            Overflow::Saturating => Some(a.saturating_add(b)),
+            Overflow::Wrapping => Some(a.wrapping_add(b)),
+        }
+    }
+
+    /// Subtracts two values under the given overflow policy
+    pub fn sub_with(a: T, b: T, overflow: Overflow) -> Option<T> {
+        match overflow {
+            Overflow::Checked => a.checked_sub(b),
+            Overflow::Saturating => Some(a.saturating_sub(b)),
+            Overflow::Wrapping => Some(a.wrapping_sub(b)),
+        }
+    }
+
+    /// Multiplies two values under the given overflow policy
+    pub fn mul_with(a: T, b: T, overflow: Overflow) -> Option<T> {
+        match overflow {
+            Overflow::Checked => a.checked_mul(b),
+            Overflow::Saturating => Some(a.saturating_mul(b)),
+            Overflow::Wrapping => Some(a.wrapping_mul(b)),
+        }
+    }
+
+    pub fn square_with(value: T, overflow: Overflow) -> Option<T> {
+        Self::mul_with(value, value, overflow)
+    }
+
+    pub fn cube_with(value: T, overflow: Overflow) -> Option<T> {
+        Self::mul_with(Self::square_with(value, overflow)?, value, overflow)
+    }
+
+    pub fn double_with(value: T, overflow: Overflow) -> Option<T> {
+        Self::add_with(value, value, overflow)
+    }
+
+    /// Raises `value` to an integer power by repeated squaring, keeping exact types exact
+    ///
+    /// Uses `T::default_policy`, so an integer result rounds to the nearest value and
+    /// panics if it overflows, including zero to a negative power; use `powi_with` to
+    /// handle that case. Floats follow IEEE 754.
+    pub fn powi(value: T, exp: i32) -> T {
+        Self::powi_with(value, exp, T::default_policy()).expect("powi overflowed")
+    }
+
+    /// Raises `value` to an integer power under the given overflow and rounding policy
+    ///
+    /// A negative power that `T` cannot hold exactly, such as `2^-1` for an integer, is
+    /// rounded per the policy, and zero to a negative power is treated as an overflow.
+    pub fn powi_with(value: T, exp: i32, policy: Policy) -> Option<T> {
+        let mut base = value;
+        let mut remaining = exp.unsigned_abs();
+        let mut result = T::one();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = Self::mul_with(result, base, policy.overflow)?;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                base = Self::mul_with(base, base, policy.overflow)?;
+            }
+        }
+        if exp >= 0 {
+            return Some(result);
+        }
+        if result != T::zero() {
+            let inverse = T::one() / result;
+            if result.checked_mul(inverse) == Some(T::one()) {
+                return Some(inverse);
+            }
+        }
+        // The reciprocal does not fit exactly, or zero has none, so round it like any float result
+        Self::from_f64_with(1.0 / result.to_f64(), policy)
+    }
+
+    /// The magnitude of `value`
+    ///
+    /// Panics if it overflows, which only happens for `MIN` of a signed integer; use
+    /// `abs_with` to handle that case.
+    pub fn abs(value: T) -> T {
+        Self::abs_with(value, T::default_policy()).expect("abs overflowed")
+    }
+
+    /// The magnitude of `value` under the given overflow policy, which matters for `MIN` of a signed integer
+    pub fn abs_with(value: T, policy: Policy) -> Option<T> {
+        if value < T::zero() {
+            Self::sub_with(T::zero(), value, policy.overflow)
+        } else {
+            Some(value)
+        }
+    }
+
+    /// The smaller of two values, `a` when they are unordered
+    pub fn min(a: T, b: T) -> T {
+        if b < a { b } else { a }
+    }
+
+    /// The larger of two values, `a` when they are unordered
+    pub fn max(a: T, b: T) -> T {
+        if b > a { b } else { a }
+    }
+
+    /// Restricts `value` to the range `min..=max`
+    ///
+    /// Panics if `min` is greater than `max`.
+    pub fn clamp(value: T, min: T, max: T) -> T {
+        assert!(min <= max, "clamp requires min <= max, got {min} > {max}");
+        Self::max(min, Self::min(value, max))
+    }
+
+    // The remaining functions need irrational results, so they round trip through `f64`.
+    // The plain versions convert back with `T::from_f64`, rounding integers to the nearest
+    // value; the `_with` versions round and handle overflow per the given policy.
+
+    pub fn sqrt(value: T) -> T {
+        T::from_f64(value.to_f64().sqrt())
+    }
+
+    /// The length of the hypotenuse of a right triangle with legs `a` and `b`
+    pub fn hypot(a: T, b: T) -> T {
+        T::from_f64(a.to_f64().hypot(b.to_f64()))
+    }
+
+    /// The sine of an angle in radians
+    pub fn sin(radians: T) -> T {
+        T::from_f64(radians.to_f64().sin())
+    }
+
+    /// The cosine of an angle in radians
+    pub fn cos(radians: T) -> T {
+        T::from_f64(radians.to_f64().cos())
+    }
+
+    /// The angle in radians of the point `(x, y)` from the positive x axis
+    pub fn atan2(y: T, x: T) -> T {
+        T::from_f64(y.to_f64().atan2(x.to_f64()))
+    }
+
+    pub fn sqrt_with(value: T, policy: Policy) -> Option<T> {
+        Self::from_f64_with(value.to_f64().sqrt(), policy)
+    }
+
+    pub fn hypot_with(a: T, b: T, policy: Policy) -> Option<T> {
+        Self::from_f64_with(a.to_f64().hypot(b.to_f64()), policy)
+    }
+
+    pub fn sin_with(radians: T, policy: Policy) -> Option<T> {
+        Self::from_f64_with(radians.to_f64().sin(), policy)
+    }
+
+    pub fn cos_with(radians: T, policy: Policy) -> Option<T> {
+        Self::from_f64_with(radians.to_f64().cos(), policy)
+    }
+
+    pub fn atan2_with(y: T, x: T, policy: Policy) -> Option<T> {
+        Self::from_f64_with(y.to_f64().atan2(x.to_f64()), policy)
+    }
+
+    /// Converts a float intermediate into `T`, rounding and handling overflow per the policy
+    pub fn from_f64_with(value: f64, policy: Policy) -> Option<T> {
+        match policy.overflow {
+            Overflow::Checked => T::checked_from_f64(value, policy.rounding),
+            Overflow::Saturating => Some(T::from_f64_rounded(value, policy.rounding)),
+            Overflow::Wrapping => Some(T::wrapping_from_f64(value, policy.rounding)),
+        }
     }
 }
 
+/// Converts the implementing type into an `f64`
+///
+/// Integers wider than 53 bits round to the nearest representable `f64`.
 pub trait ToFloat {
     fn to_f64(self) -> f64;
 }
 
-impl ToFloat for i32 {
-    fn to_f64(self) -> f64 {
-        self as f64
-    }
+macro_rules! impl_to_float {
+    ($($t:ty),*) => {
+        $(
+            impl ToFloat for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
 }
 
-impl ToFloat for i64 {
-    fn to_f64(self) -> f64 {
-        self as f64
-    }
-}
+impl_to_float!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
 
-impl ToFloat for f32 {
-    fn to_f64(self) -> f64 {
-        self as f64
-    }
+/// How a float is rounded when it is converted into an integer
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest integer, halfway cases away from zero
+    #[default]
+    Nearest,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Drop the fractional part, like an `as` cast
+    Truncate,
 }
 
-impl ToFloat for usize {
-    fn to_f64(self) -> f64 {
-        self as f64
+impl Rounding {
+    pub fn apply(self, f: f64) -> f64 {
+        match self {
+            Self::Nearest => f.round(),
+            Self::Floor => f.floor(),
+            Self::Ceil => f.ceil(),
+            Self::Truncate => f.trunc(),
+        }
     }
 }
 
-impl ToFloat for u32 {
-    fn to_f64(self) -> f64 {
-        self as f64
-    }
+/// The overflow and rounding behaviour used when a calculation produces a `T`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Policy {
+    pub overflow: Overflow,
+    pub rounding: Rounding,
 }
 
-impl ToFloat for u64 {
-    fn to_f64(self) -> f64 {
-        self as f64
+impl Policy {
+    pub fn new(overflow: Overflow, rounding: Rounding) -> Self {
+        Self {
+            overflow,
+            rounding,
+        }
     }
 }
 
-pub trait FromFloat {
-    fn from_f64(f: f64) -> Self;
+impl From<Overflow> for Policy {
+    fn from(overflow: Overflow) -> Self {
+        Self::new(overflow, Rounding::default())
+    }
 }
 
-impl FromFloat for i32 {
-    fn from_f64(f: f64) -> Self {
-        f as Self
+impl From<Rounding> for Policy {
+    fn from(rounding: Rounding) -> Self {
+        Self::new(Overflow::default(), rounding)
     }
 }
 
-impl FromFloat for i64 {
-    fn from_f64(f: f64) -> Self {
-        f as Self
+/// Converts an `f64` into the implementing type
+///
+/// Integers apply the given `Rounding` first. Out of range values then either saturate at
+/// `MIN`/`MAX`, wrap, or are rejected, and NaN becomes `0` (or `None` when checked).
+/// Floats keep the fractional part and ignore the rounding; an `f32` that cannot hold
+/// the value becomes infinite, and NaN stays NaN (or `None` when checked).
+pub trait FromFloat: Sized {
+    /// Converts `f`, clamping values outside the type's range to its bounds
+    fn from_f64_rounded(f: f64, rounding: Rounding) -> Self;
+
+    /// Converts `f`, returning `None` if it is NaN or outside the type's range
+    fn checked_from_f64(f: f64, rounding: Rounding) -> Option<Self>;
+
+    /// Converts `f`, wrapping values outside the type's range around its bounds
+    fn wrapping_from_f64(f: f64, rounding: Rounding) -> Self {
+        Self::from_f64_rounded(f, rounding)
     }
-}
 
-impl FromFloat for usize {
+    /// Converts `f` using the default `Rounding::Nearest`
     fn from_f64(f: f64) -> Self {
-        f as Self
+        Self::from_f64_rounded(f, Rounding::default())
     }
 }
 
-impl FromFloat for u32 {
-    fn from_f64(f: f64) -> Self {
-        f as Self
-    }
+macro_rules! impl_from_float_int {
+    ($($t:ty),*) => {
+        $(
+            impl FromFloat for $t {
+                fn from_f64_rounded(f: f64, rounding: Rounding) -> Self {
+                    // `as` saturates at the bounds and maps NaN to zero
+                    rounding.apply(f) as Self
+                }
+
+                fn checked_from_f64(f: f64, rounding: Rounding) -> Option<Self> {
+                    let rounded = rounding.apply(f);
+                    // MAX + 1 is a power of two, so the upper bound stays exact as an f64
+                    let in_range = rounded >= <$t>::MIN as f64 && rounded < <$t>::MAX as f64 + 1.0;
+                    in_range.then_some(rounded as Self)
+                }
+
+                fn wrapping_from_f64(f: f64, rounding: Rounding) -> Self {
+                    let rounded = rounding.apply(f);
+                    if !rounded.is_finite() {
+                        return Self::from_f64_rounded(f, rounding);
+                    }
+                    // `%` is exact for floats, unlike `rem_euclid`, which rounds small negative
+                    // values up to the modulus itself. The wrap then happens in integer space.
+                    let remainder = rounded % 2_f64.powi(<$t>::BITS as i32);
+                    let magnitude = remainder.abs() as u128;
+                    let wrapped = if remainder < 0.0 { magnitude.wrapping_neg() } else { magnitude };
+                    wrapped as Self
+                }
+            }
+        )*
+    };
 }
 
-impl FromFloat for u64 {
-    fn from_f64(f: f64) -> Self {
-        f as Self
-    }
+macro_rules! impl_from_float_float {
+    ($($t:ty),*) => {
+        $(
+            impl FromFloat for $t {
+                fn from_f64_rounded(f: f64, _rounding: Rounding) -> Self {
+                    f as Self
+                }
+
+                fn checked_from_f64(f: f64, _rounding: Rounding) -> Option<Self> {
+                    Some(f as Self).filter(|v| v.is_finite())
+                }
+            }
+        )*
+    };
 }
 
-impl FromFloat for f32 {
-    fn from_f64(f: f64) -> Self {
-        f as Self
-    }
-}
\ No newline at end of file
+impl_from_float_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_from_float_float!(f32, f64);
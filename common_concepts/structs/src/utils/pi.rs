@@ -0,0 +1,138 @@
+use std::{
+    f64::consts::PI,
+    fmt::{self, Display},
+};
+
+use super::{Ops, Policy, Scalar, ToFloat};
+
+/// An exact result of the form `numer / denom * π^power`
+///
+/// Keeps answers like the area of a circle as `100 π` instead of baking in an
+/// approximation of pi, and only turns them into a number when asked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PiMultiple<T>
+where T:
+    Scalar
+{
+    numer: T,
+    denom: T,
+    power: u32,
+}
+
+impl<T> PiMultiple<T>
+where T:
+    Scalar
+{
+    /// Builds the multiple with a positive denominator, in lowest terms when both parts are whole
+    ///
+    /// Whole float coefficients are reduced the same way, so `8000.0 / 6.0` becomes `4000/3`
+    /// instead of collapsing to `1333.33`. Other fractions are kept as given.
+    pub fn new(numer: T, denom: T, power: u32) -> Self {
+        assert!(denom != T::zero(), "denominator must not be zero");
+        let divisor = common_divisor(numer, denom);
+        let (numer, denom) = (numer / divisor, denom / divisor);
+        if denom < T::zero() {
+            Self { numer: T::zero() - numer, denom: T::zero() - denom, power }
+        } else {
+            Self { numer, denom, power }
+        }
+    }
+
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    /// Evaluates the multiple as any scalar, rounding with the default policy
+    pub fn evaluate<U>(&self) -> U
+    where U:
+        Scalar
+    {
+        U::from_f64(self.to_f64())
+    }
+
+    /// Evaluates the multiple as any scalar, `None` if a checked conversion overflowed
+    pub fn evaluate_with<U>(&self, policy: Policy) -> Option<U>
+    where U:
+        Scalar
+    {
+        Ops::from_f64_with(self.to_f64(), policy)
+    }
+}
+
+/// Whether `value` is a whole number small enough for `f64` to hold every step of Euclid's algorithm
+fn is_whole<T>(value: T) -> bool
+where T:
+    Scalar
+{
+    let value = value.to_f64();
+    value.fract() == 0.0 && value.abs() <= 2_f64.powi(53)
+}
+
+/// The greatest common divisor of two whole numbers, by Euclid's algorithm
+///
+/// Integers divide exactly, while other types truncate each quotient through `f64`. Gives one
+/// when either value is not whole or the search does not settle, leaving the fraction as it was.
+fn common_divisor<T>(a: T, b: T) -> T
+where T:
+    Scalar
+{
+    if !is_whole(a) || !is_whole(b) {
+        return T::one();
+    }
+    let (mut a, mut b) = (a, b);
+    for _ in 0..128 {
+        if b == T::zero() {
+            return if a == T::zero() { T::one() } else { Ops::abs(a) };
+        }
+        let quotient = a / b;
+        let whole = quotient.to_f64().trunc();
+        let quotient = if quotient.to_f64() == whole { quotient } else { T::from_f64(whole) };
+        (a, b) = (b, a - quotient * b);
+    }
+    T::one()
+}
+
+impl<T> ToFloat for PiMultiple<T>
+where T:
+    Scalar
+{
+    fn to_f64(self) -> f64 {
+        self.numer.to_f64() / self.denom.to_f64() * PI.powi(self.power as i32)
+    }
+}
+
+impl<T> Display for PiMultiple<T>
+where T:
+    Scalar
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = self.numer == T::one() && self.denom == T::one();
+
+        // Write `π` rather than `1 π`
+        if !unit || self.power == 0 {
+            if self.denom == T::one() {
+                write!(f, "{}", self.numer)?;
+            } else {
+                write!(f, "{}/{}", self.numer, self.denom)?;
+            }
+            if self.power > 0 {
+                write!(f, " ")?;
+            }
+        }
+        match self.power {
+            0 => Ok(()),
+            1 => write!(f, "π"),
+            2 => write!(f, "π²"),
+            3 => write!(f, "π³"),
+            power => write!(f, "π^{power}"),
+        }
+    }
+}
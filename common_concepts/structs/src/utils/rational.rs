@@ -0,0 +1,312 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use super::{FromFloat, Rounding, Scalar, ToFloat};
+
+/// An integer type that can back a `Rational`
+pub trait Integer: Scalar + Rem<Output=Self> + Ord {}
+
+impl<T> Integer for T
+where T:
+    Scalar + Rem<Output=T> + Ord
+{}
+
+/// An exact fraction, always kept in lowest terms with a positive denominator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational<I>
+where I:
+    Integer
+{
+    numer: I,
+    denom: I,
+}
+
+impl<I> Rational<I>
+where I:
+    Integer
+{
+    /// Builds `numer / denom` in lowest terms
+    ///
+    /// Panics if `denom` is zero.
+    pub fn new(numer: I, denom: I) -> Self {
+        assert!(denom != I::zero(), "rational denominator must not be zero");
+        let (numer, denom) = if denom < I::zero() {
+            (I::zero() - numer, I::zero() - denom)
+        } else {
+            (numer, denom)
+        };
+        let divisor = gcd(numer, denom);
+        Self {
+            numer: numer / divisor,
+            denom: denom / divisor,
+        }
+    }
+
+    pub fn from_integer(value: I) -> Self {
+        Self {
+            numer: value,
+            denom: I::one(),
+        }
+    }
+
+    pub fn numer(&self) -> I {
+        self.numer
+    }
+
+    pub fn denom(&self) -> I {
+        self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == I::one()
+    }
+
+    /// Builds `numer / denom` in lowest terms, `None` if `denom` is zero or the sign flip overflows
+    fn checked_new(numer: I, denom: I) -> Option<Self> {
+        if denom == I::zero() {
+            return None;
+        }
+        let (numer, denom) = if denom < I::zero() {
+            (I::zero().checked_sub(numer)?, I::zero().checked_sub(denom)?)
+        } else {
+            (numer, denom)
+        };
+        Some(Self::new(numer, denom))
+    }
+}
+
+/// Greatest common divisor of `a` and a positive `b`
+fn gcd<I>(a: I, b: I) -> I
+where I:
+    Integer
+{
+    let mut a = if a < I::zero() { I::zero() - a } else { a };
+    let mut b = b;
+    while b != I::zero() {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Whole part and remainder of `numer / denom` for a positive `denom`, rounding the whole part down
+fn floor_div<I>(numer: I, denom: I) -> (I, I)
+where I:
+    Integer
+{
+    let (whole, rem) = (numer / denom, numer % denom);
+    if rem < I::zero() {
+        (whole - I::one(), rem + denom)
+    } else {
+        (whole, rem)
+    }
+}
+
+impl<I> Display for Rational<I>
+where I:
+    Integer
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<I> Add for Rational<I>
+where I:
+    Integer
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Scale by the lcm of the denominators to keep the intermediates small
+        let g = gcd(self.denom, rhs.denom);
+        let numer = self.numer * (rhs.denom / g) + rhs.numer * (self.denom / g);
+        Self::new(numer, self.denom / g * rhs.denom)
+    }
+}
+
+impl<I> Sub for Rational<I>
+where I:
+    Integer
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let g = gcd(self.denom, rhs.denom);
+        let numer = self.numer * (rhs.denom / g) - rhs.numer * (self.denom / g);
+        Self::new(numer, self.denom / g * rhs.denom)
+    }
+}
+
+impl<I> Mul for Rational<I>
+where I:
+    Integer
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Cancel across the fractions first so the products cannot grow needlessly
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        Self::new(
+            (self.numer / g1) * (rhs.numer / g2),
+            (self.denom / g2) * (rhs.denom / g1),
+        )
+    }
+}
+
+impl<I> Div for Rational<I>
+where I:
+    Integer
+{
+    type Output = Self;
+
+    /// Panics when dividing by zero
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.numer != I::zero(), "attempt to divide a rational by zero");
+        self * Self::new(rhs.denom, rhs.numer)
+    }
+}
+
+impl<I> PartialOrd for Rational<I>
+where I:
+    Integer
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I> Ord for Rational<I>
+where I:
+    Integer
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Cross multiplying can overflow for values that fit, so walk the continued fractions
+        // instead: compare the whole parts, then the flipped remainders, which reverses the order
+        let (mut left, mut right) = ((self.numer, self.denom), (other.numer, other.denom));
+        let mut flipped = false;
+        loop {
+            let (left_whole, left_rem) = floor_div(left.0, left.1);
+            let (right_whole, right_rem) = floor_div(right.0, right.1);
+            let order = match (left_rem == I::zero(), right_rem == I::zero()) {
+                _ if left_whole != right_whole => left_whole.cmp(&right_whole),
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => {
+                    (left, right) = ((left.1, left_rem), (right.1, right_rem));
+                    flipped = !flipped;
+                    continue;
+                }
+            };
+            return if flipped { order.reverse() } else { order };
+        }
+    }
+}
+
+impl<I> ToFloat for Rational<I>
+where I:
+    Integer
+{
+    fn to_f64(self) -> f64 {
+        self.numer.to_f64() / self.denom.to_f64()
+    }
+}
+
+/// Converts a float to the closest fraction that `I` can hold
+///
+/// The continued fraction expansion stops at the first convergent that equals `f`
+/// exactly, so `0.375` becomes `3/8` and `1.0 / 3.0` becomes `1/3`.
+/// The rounding mode is ignored because the fractional part is kept.
+impl<I> FromFloat for Rational<I>
+where I:
+    Integer
+{
+    fn from_f64_rounded(f: f64, rounding: Rounding) -> Self {
+        Self::checked_from_f64(f, rounding)
+            .unwrap_or_else(|| Self::from_integer(I::from_f64_rounded(f, rounding)))
+    }
+
+    fn checked_from_f64(f: f64, _rounding: Rounding) -> Option<Self> {
+        if !f.is_finite() {
+            return None;
+        }
+        let target = f.abs();
+        let mut x = target;
+        let (mut h0, mut h1) = (I::zero(), I::one());
+        let (mut k0, mut k1) = (I::one(), I::zero());
+
+        loop {
+            let whole = x.floor();
+            let next = I::checked_from_f64(whole, Rounding::Floor).and_then(|a| {
+                let h = a.checked_mul(h1)?.checked_add(h0)?;
+                let k = a.checked_mul(k1)?.checked_add(k0)?;
+                Some((h, k))
+            });
+            let Some((h, k)) = next else {
+                break;
+            };
+            (h0, h1) = (h1, h);
+            (k0, k1) = (k1, k);
+
+            let fraction = x - whole;
+            if fraction == 0.0 || h.to_f64() / k.to_f64() == target {
+                break;
+            }
+            x = 1.0 / fraction;
+        }
+
+        if k1 == I::zero() {
+            return None;
+        }
+        let numer = if f < 0.0 { I::zero().checked_sub(h1)? } else { h1 };
+        Some(Self { numer, denom: k1 })
+    }
+}
+
+/// Only the checked operations detect overflow; saturating and wrapping fall back to
+/// the plain operators, which behave like `I` when it overflows.
+impl<I> Scalar for Rational<I>
+where I:
+    Integer
+{
+    fn zero() -> Self {
+        Self::from_integer(I::zero())
+    }
+
+    fn one() -> Self {
+        Self::from_integer(I::one())
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.denom, rhs.denom);
+        let left = self.numer.checked_mul(rhs.denom / g)?;
+        let right = rhs.numer.checked_mul(self.denom / g)?;
+        Self::checked_new(left.checked_add(right)?, (self.denom / g).checked_mul(rhs.denom)?)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let g = gcd(self.denom, rhs.denom);
+        let left = self.numer.checked_mul(rhs.denom / g)?;
+        let right = rhs.numer.checked_mul(self.denom / g)?;
+        Self::checked_new(left.checked_sub(right)?, (self.denom / g).checked_mul(rhs.denom)?)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        Self::checked_new(
+            (self.numer / g1).checked_mul(rhs.numer / g2)?,
+            (self.denom / g2).checked_mul(rhs.denom / g1)?,
+        )
+    }
+}
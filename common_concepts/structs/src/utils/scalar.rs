@@ -0,0 +1,164 @@
+use std::{
+    cmp::{PartialEq, PartialOrd},
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Sub},
+};
+
+use super::{FromFloat, Overflow, Policy, ToFloat};
+
+/// A number that the shapes can be built from
+///
+/// Bundles every bound the shapes and `Ops` need, so a shape can be written
+/// as `Shape<T: Scalar>` and a new numeric type only has to implement this trait.
+pub trait Scalar:
+    Clone + Copy + Debug + Display + Add<Output=Self> + Sub<Output=Self>
+    + Mul<Output=Self> + Div<Output=Self> + PartialEq + PartialOrd
+    + FromFloat + ToFloat
+{
+    /// The additive identity
+    fn zero() -> Self;
+
+    /// The multiplicative identity
+    fn one() -> Self;
+
+    /// The policy `Shape::area` and `Shape3D::volume` use when none is given
+    ///
+    /// Checked by default, so an overflow panics rather than giving a wrong answer.
+    fn default_policy() -> Policy {
+        Policy::default()
+    }
+
+    /// Addition that returns `None` instead of overflowing
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+
+    /// Subtraction that returns `None` instead of overflowing
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+
+    /// Multiplication that returns `None` instead of overflowing
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+
+    /// Addition that clamps to the type's bounds instead of overflowing
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    /// Subtraction that clamps to the type's bounds instead of overflowing
+    fn saturating_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    /// Multiplication that clamps to the type's bounds instead of overflowing
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    /// Addition that wraps around the type's bounds
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    /// Subtraction that wraps around the type's bounds
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    /// Multiplication that wraps around the type's bounds
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+}
+
+macro_rules! impl_scalar_int {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$t>::saturating_mul(self, rhs)
+                }
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$t>::wrapping_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_float {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn one() -> Self {
+                    1.0
+                }
+
+                // Float operations already saturate to infinity, and NaN passes through
+                fn default_policy() -> Policy {
+                    Policy::from(Overflow::Saturating)
+                }
+
+                // Floats never wrap, so a checked result only fails when it leaves the finite range
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs).filter(|v| v.is_finite())
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    Some(self - rhs).filter(|v| v.is_finite())
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    Some(self * rhs).filter(|v| v.is_finite())
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_scalar_float!(f32, f64);